@@ -1,7 +1,7 @@
 use std::fmt;
 
 /// Display configuration for boolean fields
-#[derive(Clone, Debug, PartialEq, Eq)]
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
 pub struct BooleanDisplayConfig<T: fmt::Display + Clone + 'static> {
     /// Display value for false
     pub false_display: T,
@@ -11,6 +11,30 @@ pub struct BooleanDisplayConfig<T: fmt::Display + Clone + 'static> {
     pub null_display: Option<T>,
 }
 
+impl<T: fmt::Display + Clone + PartialEq + 'static> BooleanDisplayConfig<T> {
+    /// Checks that the true and false labels are non-empty and distinct
+    ///
+    /// # Example
+    /// ```
+    /// use dbform::libs::libs_fieldtype::booleanfield_display::BooleanDisplayConfig;
+    ///
+    /// let config = BooleanDisplayConfig { false_display: "On", true_display: "On", null_display: None };
+    /// assert!(config.validate().is_err());
+    ///
+    /// let config = BooleanDisplayConfig { false_display: "", true_display: "On", null_display: None };
+    /// assert!(config.validate().is_err());
+    /// ```
+    pub fn validate(&self) -> Result<(), &'static str> {
+        if self.true_display.to_string().is_empty() || self.false_display.to_string().is_empty() {
+            return Err("Display strings cannot be empty");
+        }
+        if self.true_display == self.false_display {
+            return Err("True and false display strings must differ");
+        }
+        Ok(())
+    }
+}
+
 /// Handles display operations for boolean fields
 pub struct BooleanDisplay<'a, T: fmt::Display + Clone + 'static> {
     value: Option<bool>,
@@ -34,4 +58,126 @@ impl<'a, T: fmt::Display + Clone + 'static> BooleanDisplay<'a, T> {
                 .map_or_else(|| "NULL".to_string(), |v| v.to_string()),
         }
     }
-}
\ No newline at end of file
+}
+
+/// Single-character display configuration for dense grids
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct CompactDisplayConfig {
+    /// Character for true
+    pub true_char: char,
+    /// Character for false
+    pub false_char: char,
+    /// Character for NULL
+    pub null_char: char,
+}
+
+impl Default for CompactDisplayConfig {
+    /// Check mark for true, cross for false and a blank for NULL.
+    fn default() -> Self {
+        Self {
+            true_char: '✓',
+            false_char: '✗',
+            null_char: ' ',
+        }
+    }
+}
+
+impl CompactDisplayConfig {
+    /// Returns the character for `value`
+    pub fn char_for(&self, value: Option<bool>) -> char {
+        match value {
+            Some(true) => self.true_char,
+            Some(false) => self.false_char,
+            None => self.null_char,
+        }
+    }
+}
+
+/// Checkbox-style indicators for terminal output
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct IndicatorConfig {
+    /// Indicator for true
+    pub true_indicator: &'static str,
+    /// Indicator for false
+    pub false_indicator: &'static str,
+    /// Indicator for NULL
+    pub null_indicator: &'static str,
+}
+
+impl Default for IndicatorConfig {
+    /// `[x]` for true, `[ ]` for false and `[-]` for NULL.
+    fn default() -> Self {
+        Self {
+            true_indicator: "[x]",
+            false_indicator: "[ ]",
+            null_indicator: "[-]",
+        }
+    }
+}
+
+impl IndicatorConfig {
+    /// Returns the indicator for `value`
+    pub fn indicator_for(&self, value: Option<bool>) -> &'static str {
+        match value {
+            Some(true) => self.true_indicator,
+            Some(false) => self.false_indicator,
+            None => self.null_indicator,
+        }
+    }
+}
+
+/// A label computed at render time.
+pub type LabelFn = Box<dyn Fn() -> String + Send + Sync>;
+
+/// Display configuration whose labels are computed each time they are rendered
+///
+/// # Example
+/// ```
+/// use dbform::libs::libs_fieldtype::booleanfield_display::DynamicDisplayConfig;
+///
+/// let since = 2021;
+/// let config = DynamicDisplayConfig::new(
+///     || "No".to_string(),
+///     move || format!("Yes (since {})", since),
+///     || "Unknown".to_string(),
+/// );
+/// assert_eq!(config.label_for(Some(true)), "Yes (since 2021)");
+/// ```
+pub struct DynamicDisplayConfig {
+    /// Label for false
+    pub false_display: LabelFn,
+    /// Label for true
+    pub true_display: LabelFn,
+    /// Label for NULL
+    pub null_display: LabelFn,
+}
+
+impl DynamicDisplayConfig {
+    pub fn new(
+        false_display: impl Fn() -> String + Send + Sync + 'static,
+        true_display: impl Fn() -> String + Send + Sync + 'static,
+        null_display: impl Fn() -> String + Send + Sync + 'static,
+    ) -> Self {
+        Self {
+            false_display: Box::new(false_display),
+            true_display: Box::new(true_display),
+            null_display: Box::new(null_display),
+        }
+    }
+
+    /// Computes the label for `value`
+    pub fn label_for(&self, value: Option<bool>) -> String {
+        match value {
+            Some(true) => (self.true_display)(),
+            Some(false) => (self.false_display)(),
+            None => (self.null_display)(),
+        }
+    }
+}
+
+impl fmt::Debug for DynamicDisplayConfig {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("DynamicDisplayConfig")
+            .finish_non_exhaustive()
+    }
+}
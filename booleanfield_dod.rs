@@ -1,11 +1,322 @@
+use std::borrow::Cow;
+use std::cmp::Ordering;
+use std::collections::{BTreeSet, HashMap};
+use std::env;
+use std::error::Error;
 use std::fmt;
+use std::hash::{Hash, Hasher};
+use std::io::{self, Read, Write};
+use std::iter::{Product, Sum};
 use std::marker::PhantomData;
+use std::ops::Not;
+use std::rc::Rc;
+use std::str::FromStr;
+use std::sync::Arc;
 
 // Import display components from the new module
-use super::booleanfield_display::BooleanDisplayConfig;
+use super::booleanfield_display::{
+    BooleanDisplay, BooleanDisplayConfig, CompactDisplayConfig, DynamicDisplayConfig,
+    IndicatorConfig,
+};
+
+// --- Errors ---
+
+/// Errors produced when building or parsing a `BooleanField`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum BooleanFieldError {
+    /// The input is not one of the accepted boolean tokens.
+    InvalidToken(String),
+    /// The byte is not one of the 13 valid packed states.
+    InvalidState(u8),
+    /// Reading or writing the underlying stream failed.
+    Io(io::ErrorKind),
+    /// Two fields hold different non-NULL values for the named component.
+    MergeConflict(&'static str),
+    /// No column with the given name exists.
+    UnknownColumn(String),
+    /// A NULL value was given for a NOT NULL field.
+    NullNotAllowed,
+    /// A custom validator rejected the value.
+    Validation(String),
+    /// The input is not a valid encoding in the named format.
+    InvalidEncoding(&'static str),
+    /// The integer has no boolean meaning in the requested mapping.
+    OutOfRange(i64),
+    /// Two sequences that must pair up have different lengths.
+    LengthMismatch { expected: usize, found: usize },
+}
+
+impl fmt::Display for BooleanFieldError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            BooleanFieldError::InvalidToken(token) => {
+                write!(f, "Invalid boolean token: {:?}", token)
+            }
+            BooleanFieldError::InvalidState(byte) => write!(f, "Invalid packed state: {}", byte),
+            BooleanFieldError::Io(kind) => write!(f, "I/O error: {}", kind),
+            BooleanFieldError::MergeConflict(component) => {
+                write!(f, "Conflicting {} in merge", component)
+            }
+            BooleanFieldError::UnknownColumn(name) => write!(f, "Unknown column: {}", name),
+            BooleanFieldError::NullNotAllowed => write!(f, "Field cannot be NULL"),
+            BooleanFieldError::Validation(message) => write!(f, "{}", message),
+            BooleanFieldError::InvalidEncoding(format) => write!(f, "Invalid {} encoding", format),
+            BooleanFieldError::OutOfRange(n) => write!(f, "Integer out of range: {}", n),
+            BooleanFieldError::LengthMismatch { expected, found } => {
+                write!(f, "Length mismatch: expected {}, found {}", expected, found)
+            }
+        }
+    }
+}
+
+impl Error for BooleanFieldError {}
+
+impl From<io::Error> for BooleanFieldError {
+    fn from(err: io::Error) -> Self {
+        BooleanFieldError::Io(err.kind())
+    }
+}
+
+/// Parses a boolean token into a three-state value.
+///
+/// Matching is case-insensitive and ignores surrounding whitespace:
+/// - `true`, `t`, `yes`, `y`, `on`, `1` map to `Some(true)`
+/// - `false`, `f`, `no`, `n`, `off`, `0` map to `Some(false)`
+/// - `null` and the empty string map to `None`
+fn parse_token(s: &str) -> Result<Option<bool>, BooleanFieldError> {
+    match s.trim().to_ascii_lowercase().as_str() {
+        "true" | "t" | "yes" | "y" | "on" | "1" => Ok(Some(true)),
+        "false" | "f" | "no" | "n" | "off" | "0" => Ok(Some(false)),
+        "null" | "" => Ok(None),
+        _ => Err(BooleanFieldError::InvalidToken(s.to_string())),
+    }
+}
+
+// --- SQL Dialects ---
+
+/// SQL dialects with different spellings for boolean literals.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum SqlDialect {
+    /// PostgreSQL, which spells booleans `TRUE`/`FALSE`.
+    Postgres,
+    /// MySQL, where `BOOLEAN` is `TINYINT(1)` and booleans are `1`/`0`.
+    MySql,
+    /// SQLite, which stores booleans as the integers `1`/`0`.
+    Sqlite,
+}
+
+impl SqlDialect {
+    /// Returns the boolean literal for this dialect.
+    pub fn bool_literal(self, value: bool) -> &'static str {
+        match (self, value) {
+            (SqlDialect::Postgres, true) => "TRUE",
+            (SqlDialect::Postgres, false) => "FALSE",
+            (SqlDialect::MySql | SqlDialect::Sqlite, true) => "1",
+            (SqlDialect::MySql | SqlDialect::Sqlite, false) => "0",
+        }
+    }
+
+    /// Quotes an identifier, escaping embedded quote characters.
+    pub fn quote_ident(self, ident: &str) -> String {
+        match self {
+            SqlDialect::Postgres | SqlDialect::Sqlite => {
+                format!("\"{}\"", ident.replace('"', "\"\""))
+            }
+            SqlDialect::MySql => format!("`{}`", ident.replace('`', "``")),
+        }
+    }
+}
+
+/// A boolean bound as a prepared-statement parameter instead of a SQL literal.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum BooleanParam {
+    True,
+    False,
+    Null,
+}
+
+impl From<Option<bool>> for BooleanParam {
+    fn from(value: Option<bool>) -> Self {
+        match value {
+            Some(true) => BooleanParam::True,
+            Some(false) => BooleanParam::False,
+            None => BooleanParam::Null,
+        }
+    }
+}
+
+impl From<BooleanParam> for Option<bool> {
+    fn from(param: BooleanParam) -> Self {
+        match param {
+            BooleanParam::True => Some(true),
+            BooleanParam::False => Some(false),
+            BooleanParam::Null => None,
+        }
+    }
+}
+
+/// SQLite has no boolean type, so parameters bind as the integers `1`/`0` or NULL.
+#[cfg(feature = "rusqlite")]
+impl From<BooleanParam> for rusqlite::types::Value {
+    fn from(param: BooleanParam) -> Self {
+        match param {
+            BooleanParam::True => rusqlite::types::Value::Integer(1),
+            BooleanParam::False => rusqlite::types::Value::Integer(0),
+            BooleanParam::Null => rusqlite::types::Value::Null,
+        }
+    }
+}
+
+#[cfg(feature = "rusqlite")]
+impl rusqlite::types::ToSql for BooleanParam {
+    fn to_sql(&self) -> rusqlite::Result<rusqlite::types::ToSqlOutput<'_>> {
+        Ok(rusqlite::types::ToSqlOutput::Owned((*self).into()))
+    }
+}
+
+/// Binds as a PostgreSQL `boolean`, with `BooleanParam::Null` as SQL NULL.
+#[cfg(feature = "tokio-postgres")]
+impl tokio_postgres::types::ToSql for BooleanParam {
+    fn to_sql(
+        &self,
+        ty: &tokio_postgres::types::Type,
+        out: &mut bytes::BytesMut,
+    ) -> Result<tokio_postgres::types::IsNull, Box<dyn Error + Sync + Send>> {
+        <Option<bool> as tokio_postgres::types::ToSql>::to_sql(&(*self).into(), ty, out)
+    }
+
+    fn accepts(ty: &tokio_postgres::types::Type) -> bool {
+        <bool as tokio_postgres::types::ToSql>::accepts(ty)
+    }
+
+    tokio_postgres::types::to_sql_checked!();
+}
+
+// --- Three-State Logic ---
+
+/// Two-input logical operations with three-state semantics.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum BinaryOp {
+    And,
+    Or,
+    Xor,
+}
+
+/// Three-state AND on plain values, usable in const contexts.
+///
+/// # Example
+/// ```
+/// use dbform::libs::libs_fieldtype::booleanfield_dod::{and_values, not_values, or_values, xor_values};
+///
+/// const GATE: Option<bool> = and_values(Some(true), or_values(None, Some(true)));
+/// const PARITY: Option<bool> = xor_values(GATE, not_values(Some(true)));
+/// const UNKNOWN: Option<bool> = and_values(Some(true), None);
+///
+/// assert_eq!(GATE, Some(true));
+/// assert_eq!(PARITY, Some(true));
+/// assert_eq!(UNKNOWN, None);
+/// ```
+pub const fn and_values(a: Option<bool>, b: Option<bool>) -> Option<bool> {
+    match (a, b) {
+        (Some(true), Some(true)) => Some(true),
+        (_, Some(false)) | (Some(false), _) => Some(false),
+        _ => None,
+    }
+}
+
+/// Three-state OR on plain values, usable in const contexts.
+pub const fn or_values(a: Option<bool>, b: Option<bool>) -> Option<bool> {
+    match (a, b) {
+        (Some(true), _) | (_, Some(true)) => Some(true),
+        (Some(false), Some(false)) => Some(false),
+        _ => None,
+    }
+}
+
+/// Three-state XOR on plain values, usable in const contexts.
+pub const fn xor_values(a: Option<bool>, b: Option<bool>) -> Option<bool> {
+    match (a, b) {
+        (Some(x), Some(y)) => Some(x != y),
+        _ => None,
+    }
+}
+
+/// Three-state NOT on a plain value, usable in const contexts.
+pub const fn not_values(a: Option<bool>) -> Option<bool> {
+    match a {
+        Some(value) => Some(!value),
+        None => None,
+    }
+}
+
+/// Any source of a three-state boolean value.
+///
+/// Lets generic code accept a `BooleanField`, a plain `Option<bool>` or a
+/// `bool` alike.
+///
+/// # Example
+/// ```
+/// use dbform::libs::libs_fieldtype::booleanfield_dod::{and_values, BooleanField, ThreeStateBool};
+///
+/// fn both(a: &impl ThreeStateBool, b: &impl ThreeStateBool) -> Option<bool> {
+///     and_values(a.value(), b.value())
+/// }
+///
+/// let field = BooleanField::<&str>::from(true);
+/// assert_eq!(both(&field, &true), Some(true));
+/// assert_eq!(both(&field, &Some(false)), Some(false));
+/// assert_eq!(both(&None, &field), None);
+/// ```
+pub trait ThreeStateBool {
+    /// Returns the current value, None meaning NULL.
+    fn value(&self) -> Option<bool>;
+}
+
+impl ThreeStateBool for Option<bool> {
+    fn value(&self) -> Option<bool> {
+        *self
+    }
+}
+
+impl ThreeStateBool for bool {
+    fn value(&self) -> Option<bool> {
+        Some(*self)
+    }
+}
 
 // --- Bit-Packed Data Component ---
 
+/// 64-bit FNV-1a, a fixed hasher whose output is stable across runs and builds.
+struct Fnv1aHasher(u64);
+
+impl Fnv1aHasher {
+    const OFFSET_BASIS: u64 = 0xcbf2_9ce4_8422_2325;
+    const PRIME: u64 = 0x0000_0100_0000_01b3;
+
+    fn new() -> Self {
+        Self(Self::OFFSET_BASIS)
+    }
+
+    /// Writes a length-prefixed string with a fixed little-endian length, so the
+    /// bytes fed in do not depend on the target or toolchain.
+    fn write_str(&mut self, s: &str) {
+        self.write(&(s.len() as u64).to_le_bytes());
+        self.write(s.as_bytes());
+    }
+}
+
+impl Hasher for Fnv1aHasher {
+    fn write(&mut self, bytes: &[u8]) {
+        for &byte in bytes {
+            self.0 = (self.0 ^ u64::from(byte)).wrapping_mul(Self::PRIME);
+        }
+    }
+
+    fn finish(&self) -> u64 {
+        self.0
+    }
+}
+
 /// Represents the three states of a boolean field to simplify logic.
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 enum OptionBool {
@@ -36,7 +347,11 @@ impl From<OptionBool> for Option<bool> {
 
 /// Encodes the state (not_null, default, value) into a single u8 based on the 13 valid states.
 /// Returns an error for any invalid combination.
-fn encode_state(not_null: bool, default: OptionBool, value: OptionBool) -> Result<u8, &'static str> {
+fn encode_state(
+    not_null: bool,
+    default: OptionBool,
+    value: OptionBool,
+) -> Result<u8, &'static str> {
     use OptionBool::*;
     match (not_null, default, value) {
         // N=F
@@ -80,11 +395,30 @@ fn decode_state(state: u8) -> Result<(bool, OptionBool, OptionBool), &'static st
     }
 }
 
+/// Fuzzing oracle for the packing tables, driven by `fuzz/fuzz_targets/state_roundtrip.rs`.
+///
+/// For every input byte that `PackedBooleanData::from_byte` accepts, decoding and
+/// re-encoding must give back the same byte: `encode_state(decode_state(b)) == b`.
+/// Any drift between the two tables breaks this for at least one of the 13 states.
+#[cfg(feature = "fuzz")]
+pub fn fuzz_state_roundtrip(bytes: &[u8]) {
+    for &byte in bytes {
+        if let Ok(data) = PackedBooleanData::from_byte(byte) {
+            let (not_null, default, value) = data.get_full_state();
+            assert_eq!(encode_state(not_null, default, value), Ok(byte));
+        }
+    }
+}
+
 /// A memory-optimized boolean data structure using a single byte.
 /// It stores the value, default, and not_null constraint in one u8.
 #[derive(Clone, Copy, PartialEq, Eq, Hash, Debug)]
+#[repr(transparent)]
 pub(crate) struct PackedBooleanData(u8);
 
+// The whole field state must fit in a single byte.
+const _: () = assert!(std::mem::size_of::<PackedBooleanData>() == 1);
+
 impl Default for PackedBooleanData {
     /// The default state is N=F, D=N, V=N (state 10), which matches the old `new()` behavior.
     fn default() -> Self {
@@ -93,22 +427,101 @@ impl Default for PackedBooleanData {
 }
 
 impl PackedBooleanData {
+    /// Size of the packed state in bytes.
+    pub const SIZE: usize = std::mem::size_of::<Self>();
+
     /// Decodes the byte to get the full state.
     /// Panics if the internal state is invalid, which should never happen with correct logic.
+    /// The `BooleanOps` operations that rebuild the state (`set_not_null`, `set_default`,
+    /// `set_value`, the logical operations, merges) go through this and can still panic;
+    /// the read accessors use `try_get_full_state` instead.
     fn get_full_state(&self) -> (bool, OptionBool, OptionBool) {
         decode_state(self.0).expect("Internal state of PackedBooleanData is invalid")
     }
 
+    /// Decodes the byte into (not_null, default, value), or reports an invalid state.
+    pub fn try_get_full_state(
+        &self,
+    ) -> Result<(bool, Option<bool>, Option<bool>), BooleanFieldError> {
+        decode_state(self.0)
+            .map(|(not_null, default, value)| (not_null, default.into(), value.into()))
+            .map_err(|_| BooleanFieldError::InvalidState(self.0))
+    }
+
+    /// Returns the value, or NULL if the byte is not a valid state.
     pub fn value(&self) -> Option<bool> {
-        self.get_full_state().2.into()
+        self.try_get_full_state()
+            .map_or(None, |(_, _, value)| value)
     }
 
+    /// Returns the default, or NULL if the byte is not a valid state.
     pub fn default_value(&self) -> Option<bool> {
-        self.get_full_state().1.into()
+        self.try_get_full_state()
+            .map_or(None, |(_, default, _)| default)
     }
 
+    /// Returns the NOT NULL flag, or false if the byte is not a valid state.
     pub fn not_null(&self) -> bool {
-        self.get_full_state().0
+        self.try_get_full_state()
+            .is_ok_and(|(not_null, _, _)| not_null)
+    }
+
+    /// Builds packed data from a raw state byte, rejecting invalid states.
+    pub fn from_byte(byte: u8) -> Result<Self, BooleanFieldError> {
+        decode_state(byte)
+            .map(|_| Self(byte))
+            .map_err(|_| BooleanFieldError::InvalidState(byte))
+    }
+
+    /// Builds packed data from a raw byte without validating it.
+    ///
+    /// Only for exercising error handling on corrupt states; downstream tests
+    /// reach it through `BooleanField::from_byte_unchecked`. The result is not
+    /// safe for logic: `try_get_full_state` reports an invalid byte, `value`,
+    /// `default_value` and `not_null` fall back to NULL, NULL and false, and
+    /// the `BooleanOps` operations that re-encode the state still panic.
+    /// `to_byte` always returns the byte unchanged.
+    #[cfg(any(test, feature = "unchecked"))]
+    pub fn from_byte_unchecked(byte: u8) -> Self {
+        Self(byte)
+    }
+
+    /// Maps a legacy or corrupt state byte to the nearest valid state, for
+    /// `BooleanField::from_legacy_byte`.
+    ///
+    /// Valid bytes are returned unchanged. Otherwise, for bytes below 32:
+    /// - value bits `11` (a stray bit on the value) read as NULL
+    /// - default bits `11` read as no default
+    /// - under NOT NULL, a NULL value takes the default and a NULL default takes
+    ///   the value; if both are NULL the byte is unrecoverable
+    ///
+    /// Bytes with any of bits 5-7 set are unrecoverable and give None.
+    pub fn repair_byte(byte: u8) -> Option<Self> {
+        if byte >= 32 {
+            return None;
+        }
+        let field = |bits: u8| match bits & 0b11 {
+            0 => OptionBool::False,
+            1 => OptionBool::True,
+            _ => OptionBool::Null,
+        };
+        let not_null = byte & 0b1_0000 != 0;
+        let mut default = field(byte >> 2);
+        let mut value = field(byte);
+        if not_null {
+            match (default, value) {
+                (OptionBool::Null, OptionBool::Null) => return None,
+                (OptionBool::Null, _) => default = value,
+                (_, OptionBool::Null) => value = default,
+                _ => {}
+            }
+        }
+        encode_state(not_null, default, value).ok().map(Self)
+    }
+
+    /// Returns the raw state byte.
+    pub fn to_byte(self) -> u8 {
+        self.0
     }
 }
 
@@ -139,6 +552,22 @@ impl BooleanOps {
         data.0 = encode_state(true, default, value).unwrap();
     }
 
+    /// Sets the NOT NULL constraint, failing instead of coercing a NULL value
+    pub fn try_set_not_null(data: &mut PackedBooleanData) -> Result<(), BooleanFieldError> {
+        if data.value().is_none() {
+            return Err(BooleanFieldError::NullNotAllowed);
+        }
+        Self::set_not_null(data);
+        Ok(())
+    }
+
+    /// Drops the NOT NULL constraint, keeping the value and default
+    pub fn set_nullable(data: &mut PackedBooleanData) {
+        let (_, default, value) = data.get_full_state();
+        // A nullable field accepts every value and default.
+        data.0 = encode_state(false, default, value).unwrap();
+    }
+
     /// Sets a default value
     pub fn set_default(data: &mut PackedBooleanData, new_default: bool) {
         let (not_null, _, mut value) = data.get_full_state();
@@ -153,6 +582,27 @@ impl BooleanOps {
         data.0 = encode_state(not_null, new_default_ob, value).unwrap();
     }
 
+    /// Clears the default value; NOT NULL fields must keep theirs
+    pub fn clear_default(data: &mut PackedBooleanData) -> Result<(), String> {
+        let (not_null, _, value) = data.get_full_state();
+        if not_null {
+            return Err("NOT NULL field must have a default".to_string());
+        }
+
+        // A nullable field accepts a NULL default with any value.
+        data.0 = encode_state(false, OptionBool::Null, value).unwrap();
+        Ok(())
+    }
+
+    /// Drops a nullable field's literal default when it equals the value
+    pub fn canonicalize(data: &mut PackedBooleanData) {
+        let (not_null, default, value) = data.get_full_state();
+        if !not_null && default != OptionBool::Null && default == value {
+            // A nullable field accepts a NULL default with any value.
+            data.0 = encode_state(false, OptionBool::Null, value).unwrap();
+        }
+    }
+
     /// Sets a new value with validation
     pub fn set_value(data: &mut PackedBooleanData, value: Option<bool>) -> Result<(), String> {
         let (not_null, default, _) = data.get_full_state();
@@ -175,56 +625,456 @@ impl BooleanOps {
 
     /// Logical AND operation with three-state logic
     pub fn and(a: &PackedBooleanData, b: &PackedBooleanData) -> PackedBooleanData {
-        let value = match (a.value(), b.value()) {
-            (Some(true), Some(true)) => Some(true),
-            (_, Some(false)) | (Some(false), _) => Some(false),
-            _ => None,
-        };
+        let value = and_values(a.value(), b.value());
         // Result inherits constraints from 'a'.
-        let (not_null, default, _) = a.get_full_state();
-        let new_state = encode_state(not_null, default, value.into()).unwrap();
-        PackedBooleanData(new_state)
+        Self::derive(a, value)
     }
 
     /// Logical OR operation with three-state logic
     pub fn or(a: &PackedBooleanData, b: &PackedBooleanData) -> PackedBooleanData {
-        let value = match (a.value(), b.value()) {
-            (Some(true), _) | (_, Some(true)) => Some(true),
-            (Some(false), Some(false)) => Some(false),
-            _ => None,
-        };
+        let value = or_values(a.value(), b.value());
         // Result inherits constraints from 'a'.
-        let (not_null, default, _) = a.get_full_state();
-        let new_state = encode_state(not_null, default, value.into()).unwrap();
-        PackedBooleanData(new_state)
+        Self::derive(a, value)
+    }
+
+    /// Logical XOR operation with three-state logic
+    pub fn xor(a: &PackedBooleanData, b: &PackedBooleanData) -> PackedBooleanData {
+        let value = xor_values(a.value(), b.value());
+        // Result inherits constraints from 'a'.
+        Self::derive(a, value)
     }
 
     /// Logical NOT operation with three-state logic
     pub fn not(data: &PackedBooleanData) -> PackedBooleanData {
-        let value = data.value().map(|val| !val);
+        let value = not_values(data.value());
         // Result inherits constraints.
-        let (not_null, default, _) = data.get_full_state();
-        let new_state = encode_state(not_null, default, value.into()).unwrap();
+        Self::derive(data, value)
+    }
+
+    /// Builds the result of a logical operation from its source's constraints and a new value.
+    ///
+    /// A NOT NULL source combined with a NULL operand can yield a NULL result, which the
+    /// constraint cannot hold, so such a result is nullable. The default is kept either way.
+    /// With that relaxation every (source, value) pair maps to one of the 13 valid states.
+    fn derive(source: &PackedBooleanData, value: Option<bool>) -> PackedBooleanData {
+        let (not_null, default, _) = source.get_full_state();
+        let value: OptionBool = value.into();
+        let not_null = not_null && value != OptionBool::Null;
+        let new_state = encode_state(not_null, default, value)
+            .expect("Logical operations must always produce a valid state");
+        PackedBooleanData(new_state)
+    }
+
+    /// Checks that each side's NOT NULL constraint holds for the other's value and default
+    pub fn constraints_compatible(a: &PackedBooleanData, b: &PackedBooleanData) -> bool {
+        let satisfies = |constraint: &PackedBooleanData, data: &PackedBooleanData| {
+            !constraint.not_null() || (data.value().is_some() && data.default_value().is_some())
+        };
+        satisfies(a, b) && satisfies(b, a)
+    }
+
+    /// Merges two states, keeping the strictest constraints and the non-NULL value and default.
+    /// Fails when the constraints are not `constraints_compatible`.
+    pub fn merge(
+        a: &PackedBooleanData,
+        b: &PackedBooleanData,
+    ) -> Result<PackedBooleanData, BooleanFieldError> {
+        if !Self::constraints_compatible(a, b) {
+            return Err(BooleanFieldError::MergeConflict("NOT NULL constraint"));
+        }
+        let pick = |x: Option<bool>, y: Option<bool>, component| match (x, y) {
+            (Some(x), Some(y)) if x != y => Err(BooleanFieldError::MergeConflict(component)),
+            _ => Ok(x.or(y)),
+        };
+        let not_null = a.not_null() || b.not_null();
+        let default = pick(a.default_value(), b.default_value(), "default")?;
+        let value = pick(a.value(), b.value(), "value")?;
+
+        // A NOT NULL side always carries a value and a default, so this encoding cannot fail.
+        let new_state = encode_state(not_null, default.into(), value.into())
+            .expect("Merged state must be valid");
+        Ok(PackedBooleanData(new_state))
+    }
+
+    /// Merges two states taking the winner's value and default, with the strictest NOT NULL.
+    /// The loser only fills in a value or default the NOT NULL constraint requires.
+    pub fn merge_prefer(
+        winner: &PackedBooleanData,
+        loser: &PackedBooleanData,
+    ) -> PackedBooleanData {
+        let not_null = winner.not_null() || loser.not_null();
+        let mut default = winner.default_value();
+        let mut value = winner.value();
+        if not_null {
+            // The NOT NULL side always carries both, so neither stays NULL.
+            default = default.or(loser.default_value());
+            value = value.or(loser.value());
+        }
+        let new_state = encode_state(not_null, default.into(), value.into())
+            .expect("Merged state must be valid");
         PackedBooleanData(new_state)
     }
 
+    /// Returns the DEFAULT clause for the dialect, or None when no default is set
+    pub fn default_clause(data: &PackedBooleanData, dialect: SqlDialect) -> Option<String> {
+        data.default_value()
+            .map(|default_val| format!("DEFAULT {}", dialect.bool_literal(default_val)))
+    }
+
     /// Returns the SQL type definition as a string with all constraints
     pub fn to_sql(data: &PackedBooleanData) -> String {
+        let default_clause = Self::default_clause(data, SqlDialect::Postgres);
+        Self::to_sql_with_default(data, default_clause.as_deref())
+    }
+
+    /// Returns the SQL type definition using the given DEFAULT clause instead of the literal one
+    pub fn to_sql_with_default(data: &PackedBooleanData, default_clause: Option<&str>) -> String {
         let mut sql = "BOOLEAN".to_string();
 
         if data.not_null() {
             sql.push_str(" NOT NULL");
         }
 
-        if let Some(default_val) = data.default_value() {
-            sql.push_str(" DEFAULT ");
-            if default_val {
-                sql.push_str("TRUE");
+        if let Some(default_clause) = default_clause {
+            sql.push(' ');
+            sql.push_str(default_clause);
+        }
+        sql
+    }
+
+    /// Counts the fields holding `true` in a packed column.
+    ///
+    /// The value lives in the two low bits of each byte and `true` is the only
+    /// value encoded as `01`, so eight fields are tested per `u64` word with a
+    /// mask instead of decoding every byte.
+    pub fn popcount_true(data: &[PackedBooleanData]) -> usize {
+        const LOW_BITS: u64 = 0x0101_0101_0101_0101;
+
+        let mut chunks = data.chunks_exact(8);
+        let mut count = 0;
+        for chunk in &mut chunks {
+            let mut bytes = [0u8; 8];
+            for (byte, field) in bytes.iter_mut().zip(chunk) {
+                *byte = field.0;
+            }
+            let word = u64::from_le_bytes(bytes);
+            // Bit 0 set and bit 1 clear in a byte means the value is TRUE.
+            count += (word & !(word >> 1) & LOW_BITS).count_ones() as usize;
+        }
+        count += chunks
+            .remainder()
+            .iter()
+            .filter(|field| field.0 & 0b11 == 0b01)
+            .count();
+        count
+    }
+
+    /// Returns the (a, b, result) rows of `op` for all nine value pairs,
+    /// in the order true, false, NULL for `a` and then for `b`
+    pub fn truth_table(op: BinaryOp) -> [(Option<bool>, Option<bool>, Option<bool>); 9] {
+        const VALUES: [Option<bool>; 3] = [Some(true), Some(false), None];
+        std::array::from_fn(|i| {
+            let (a, b) = (VALUES[i / 3], VALUES[i % 3]);
+            let mut a_data = Self::new_data();
+            let mut b_data = Self::new_data();
+            // Nullable data accepts every value.
+            Self::set_value(&mut a_data, a).unwrap();
+            Self::set_value(&mut b_data, b).unwrap();
+            let result = match op {
+                BinaryOp::And => Self::and(&a_data, &b_data),
+                BinaryOp::Or => Self::or(&a_data, &b_data),
+                BinaryOp::Xor => Self::xor(&a_data, &b_data),
+            };
+            (a, b, result.value())
+        })
+    }
+
+    /// Counts the rows whose values differ; NULL differs from either value but equals NULL.
+    /// Compares the value bits of eight rows per 64-bit word.
+    pub fn hamming_distance(
+        a: &[PackedBooleanData],
+        b: &[PackedBooleanData],
+    ) -> Result<usize, BooleanFieldError> {
+        const LOW_BITS: u64 = 0x0101_0101_0101_0101;
+
+        if a.len() != b.len() {
+            return Err(BooleanFieldError::LengthMismatch {
+                expected: a.len(),
+                found: b.len(),
+            });
+        }
+        let word = |chunk: &[PackedBooleanData]| {
+            let mut bytes = [0u8; 8];
+            for (byte, field) in bytes.iter_mut().zip(chunk) {
+                *byte = field.0;
+            }
+            u64::from_le_bytes(bytes)
+        };
+
+        let mut a_chunks = a.chunks_exact(8);
+        let mut b_chunks = b.chunks_exact(8);
+        let mut distance = 0;
+        for (x, y) in (&mut a_chunks).zip(&mut b_chunks) {
+            // Each value has its own bit pattern, so rows differ iff bits 0-1 differ.
+            let diff = word(x) ^ word(y);
+            distance += ((diff | diff >> 1) & LOW_BITS).count_ones() as usize;
+        }
+        distance += a_chunks
+            .remainder()
+            .iter()
+            .zip(b_chunks.remainder())
+            .filter(|(x, y)| (x.0 ^ y.0) & 0b11 != 0)
+            .count();
+        Ok(distance)
+    }
+
+    /// Counts the (true, false, NULL) values in a column
+    pub fn count_values(data: &[PackedBooleanData]) -> (usize, usize, usize) {
+        data.iter()
+            .fold((0, 0, 0), |(t, f, n), field| match field.value() {
+                Some(true) => (t + 1, f, n),
+                Some(false) => (t, f + 1, n),
+                None => (t, f, n + 1),
+            })
+    }
+
+    /// Counts how many of true, false and NULL appear in a column (0 to 3)
+    pub fn distinct_value_count(data: &[PackedBooleanData]) -> usize {
+        let mut seen = [false; 3];
+        for field in data {
+            let slot = match field.value() {
+                Some(true) => 0,
+                Some(false) => 1,
+                None => 2,
+            };
+            seen[slot] = true;
+            if seen == [true; 3] {
+                break;
+            }
+        }
+        seen.iter().filter(|&&seen| seen).count()
+    }
+
+    /// Shannon entropy in bits of the true/false split, ignoring NULLs.
+    /// A 50/50 split gives 1.0, a constant column 0.0, and an empty or all-NULL column 0.0.
+    pub fn balance(data: &[PackedBooleanData]) -> f64 {
+        let (trues, falses, _) = Self::count_values(data);
+        let total = (trues + falses) as f64;
+        [trues, falses]
+            .iter()
+            .filter(|&&count| count > 0)
+            .map(|&count| {
+                let p = count as f64 / total;
+                p * (1.0 / p).log2()
+            })
+            .fold(0.0, |entropy, term| entropy + term)
+    }
+
+    /// Sets NOT NULL on every field, replacing NULL values and NULL defaults with `fill`.
+    /// Returns how many NULL values were replaced.
+    pub fn enforce_not_null_column(data: &mut [PackedBooleanData], fill: bool) -> usize {
+        let fill = Some(fill);
+        let mut coerced = 0;
+        for field in data {
+            let (_, default, value) = field.get_full_state();
+            let default = Option::<bool>::from(default).or(fill);
+            let value = Option::<bool>::from(value).or_else(|| {
+                coerced += 1;
+                fill
+            });
+            // Neither the value nor the default is NULL any more.
+            field.0 = encode_state(true, default.into(), value.into()).unwrap();
+        }
+        coerced
+    }
+
+    /// Writes a column as CSV, one cell per CRLF-terminated line (RFC 4180).
+    /// Values use the config's labels or `true`/`false`, NULL is an empty cell, and
+    /// cells containing a comma, quote or line break are quoted with `""` escapes.
+    pub fn write_csv_column<W: Write>(
+        data: &[PackedBooleanData],
+        w: &mut W,
+        config: Option<&BooleanDisplayConfig<String>>,
+    ) -> io::Result<()> {
+        for field in data {
+            let cell = match (field.value(), config) {
+                (None, _) => "",
+                (Some(true), Some(config)) => config.true_display.as_str(),
+                (Some(false), Some(config)) => config.false_display.as_str(),
+                (Some(true), None) => "true",
+                (Some(false), None) => "false",
+            };
+            if cell.contains([',', '"', '\r', '\n']) {
+                write!(w, "\"{}\"\r\n", cell.replace('"', "\"\""))?;
             } else {
-                sql.push_str("FALSE");
+                write!(w, "{}\r\n", cell)?;
             }
         }
-        sql
+        Ok(())
+    }
+
+    /// Three-state quorum: true once `k` values are TRUE, false once too few
+    /// non-FALSE values remain to reach `k`, NULL while the NULLs could go either way
+    pub fn at_least(data: &[PackedBooleanData], k: usize) -> PackedBooleanData {
+        let (mut trues, mut unknowns) = (0, 0);
+        for field in data {
+            if trues >= k {
+                break;
+            }
+            match field.value() {
+                Some(true) => trues += 1,
+                Some(false) => {}
+                None => unknowns += 1,
+            }
+        }
+        let value = if trues >= k {
+            Some(true)
+        } else if trues + unknowns < k {
+            Some(false)
+        } else {
+            None
+        };
+        Self::derive(&Self::new_data(), value)
+    }
+
+    /// Negates every value in place, keeping NULLs, defaults and NOT NULL as they are
+    pub fn negate_column(data: &mut [PackedBooleanData]) {
+        for field in data {
+            // Bit 1 clear means a non-NULL value; flipping bit 0 swaps FALSE and TRUE.
+            field.0 ^= !(field.0 >> 1) & 0b01;
+        }
+    }
+
+    /// Classifies a column as empty, constant or mixed, stopping at the first differing value
+    pub fn column_summary(data: &[PackedBooleanData]) -> ColumnSummary {
+        let Some((first, rest)) = data.split_first() else {
+            return ColumnSummary::Empty;
+        };
+        let value = first.value();
+        if rest.iter().any(|field| field.value() != value) {
+            return ColumnSummary::Mixed;
+        }
+        match value {
+            Some(true) => ColumnSummary::AllTrue,
+            Some(false) => ColumnSummary::AllFalse,
+            None => ColumnSummary::AllNull,
+        }
+    }
+
+    /// Infers NOT NULL eligibility and the modal value of a column in one pass
+    pub fn infer_constraints(data: &[PackedBooleanData]) -> InferredConstraints {
+        let (mut trues, mut falses, mut nulls) = (0usize, 0usize, 0usize);
+        for field in data {
+            match field.value() {
+                Some(true) => trues += 1,
+                Some(false) => falses += 1,
+                None => nulls += 1,
+            }
+        }
+        let inferred_default = match trues.cmp(&falses) {
+            Ordering::Greater => Some(true),
+            Ordering::Less => Some(false),
+            Ordering::Equal => None,
+        };
+        InferredConstraints {
+            can_be_not_null: nulls == 0,
+            inferred_default,
+        }
+    }
+
+    /// Element-wise AND of two columns; each result inherits the constraints of `a[i]`
+    pub fn and_columns(
+        a: &[PackedBooleanData],
+        b: &[PackedBooleanData],
+    ) -> Result<Vec<PackedBooleanData>, BooleanFieldError> {
+        Self::zip_columns(a, b, Self::and)
+    }
+
+    /// Element-wise OR of two columns; each result inherits the constraints of `a[i]`
+    pub fn or_columns(
+        a: &[PackedBooleanData],
+        b: &[PackedBooleanData],
+    ) -> Result<Vec<PackedBooleanData>, BooleanFieldError> {
+        Self::zip_columns(a, b, Self::or)
+    }
+
+    /// Checks that `a[i] -> b[i]` holds on every row under three-state logic.
+    /// Only a row where `a` is TRUE and `b` is FALSE refutes it; rows whose
+    /// implication is NULL cannot, so they count as holding.
+    pub fn column_implies(
+        a: &[PackedBooleanData],
+        b: &[PackedBooleanData],
+    ) -> Result<bool, BooleanFieldError> {
+        if a.len() != b.len() {
+            return Err(BooleanFieldError::LengthMismatch {
+                expected: a.len(),
+                found: b.len(),
+            });
+        }
+        Ok(!a
+            .iter()
+            .zip(b)
+            .any(|(x, y)| x.value() == Some(true) && y.value() == Some(false)))
+    }
+
+    /// Checks each proposed value against the constraints of the matching row without
+    /// applying anything, returning every failing index: `NullNotAllowed` for NULL on a
+    /// NOT NULL row, `InvalidState` for a row whose packed byte is corrupt.
+    /// Columns of different lengths are rejected up front with `LengthMismatch`.
+    pub fn validate_values(
+        data: &[PackedBooleanData],
+        values: &[Option<bool>],
+    ) -> Result<Vec<(usize, BooleanFieldError)>, BooleanFieldError> {
+        if data.len() != values.len() {
+            return Err(BooleanFieldError::LengthMismatch {
+                expected: data.len(),
+                found: values.len(),
+            });
+        }
+        Ok(data
+            .iter()
+            .zip(values)
+            .enumerate()
+            .filter_map(|(i, (field, value))| match field.try_get_full_state() {
+                Err(err) => Some((i, err)),
+                Ok((true, _, _)) if value.is_none() => Some((i, BooleanFieldError::NullNotAllowed)),
+                Ok(_) => None,
+            })
+            .collect())
+    }
+
+    /// Returns the indices where the two columns hold different values;
+    /// NULL differs from either value but equals NULL
+    pub fn diff_indices(
+        a: &[PackedBooleanData],
+        b: &[PackedBooleanData],
+    ) -> Result<Vec<usize>, BooleanFieldError> {
+        if a.len() != b.len() {
+            return Err(BooleanFieldError::LengthMismatch {
+                expected: a.len(),
+                found: b.len(),
+            });
+        }
+        Ok(a.iter()
+            .zip(b)
+            .enumerate()
+            .filter(|(_, (x, y))| x.value() != y.value())
+            .map(|(i, _)| i)
+            .collect())
+    }
+
+    fn zip_columns(
+        a: &[PackedBooleanData],
+        b: &[PackedBooleanData],
+        op: fn(&PackedBooleanData, &PackedBooleanData) -> PackedBooleanData,
+    ) -> Result<Vec<PackedBooleanData>, BooleanFieldError> {
+        if a.len() != b.len() {
+            return Err(BooleanFieldError::LengthMismatch {
+                expected: a.len(),
+                found: b.len(),
+            });
+        }
+        Ok(a.iter().zip(b).map(|(x, y)| op(x, y)).collect())
     }
 }
 
@@ -254,25 +1104,168 @@ impl<'a> BooleanDataView<'a> {
     }
 }
 
-/// A boolean field that combines storage optimization with display configuration.
-///
-/// This type provides a high-level interface for working with boolean values
-/// while internally using a packed representation for memory efficiency.
-///
-/// # Examples
-///
-/// ```
-/// use dbform::libs::libs_fieldtype::booleanfield_dod::BooleanField;
-///
-/// // Create a field with NOT NULL constraint and default value
-/// let field = BooleanField::<&str>::new()
-///     .not_null()
-///     .default(true);
+// --- Validation ---
+
+/// A custom rule checked by `BooleanField::set_value` after the NOT NULL check.
 ///
-/// // When NOT NULL is set, the value is automatically set to the default
-/// assert_eq!(field.get_value(), Some(false));
-/// 
-/// // After setting a value, it will be used
+/// Closures of type `Fn(Option<bool>) -> Result<(), String>` implement this trait.
+pub trait Validator: Send + Sync {
+    /// Returns an error message if `value` is not allowed.
+    fn validate(&self, value: Option<bool>) -> Result<(), String>;
+}
+
+impl<F> Validator for F
+where
+    F: Fn(Option<bool>) -> Result<(), String> + Send + Sync,
+{
+    fn validate(&self, value: Option<bool>) -> Result<(), String> {
+        self(value)
+    }
+}
+
+/// Only lets through values from a fixed set, installed by `BooleanField::restrict`.
+struct AllowedValues {
+    allowed: [bool; 3],
+}
+
+impl AllowedValues {
+    fn new(values: &[Option<bool>]) -> Self {
+        let mut allowed = [false; 3];
+        for &value in values {
+            allowed[Self::slot(value)] = true;
+        }
+        Self { allowed }
+    }
+
+    fn slot(value: Option<bool>) -> usize {
+        match value {
+            Some(false) => 0,
+            Some(true) => 1,
+            None => 2,
+        }
+    }
+}
+
+impl Validator for AllowedValues {
+    fn validate(&self, value: Option<bool>) -> Result<(), String> {
+        if self.allowed[Self::slot(value)] {
+            Ok(())
+        } else {
+            let value = value.map_or("NULL".to_string(), |value| value.to_string());
+            Err(format!("Value {} is not allowed", value))
+        }
+    }
+}
+
+/// The outcome of evaluating a field as a rule.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Decision {
+    /// Definitely true.
+    True,
+    /// Definitely false.
+    False,
+    /// Unknown because of missing (NULL) data.
+    Unknown,
+}
+
+impl From<Option<bool>> for Decision {
+    fn from(value: Option<bool>) -> Self {
+        match value {
+            Some(true) => Decision::True,
+            Some(false) => Decision::False,
+            None => Decision::Unknown,
+        }
+    }
+}
+
+impl From<Decision> for Option<bool> {
+    fn from(decision: Decision) -> Self {
+        match decision {
+            Decision::True => Some(true),
+            Decision::False => Some(false),
+            Decision::Unknown => None,
+        }
+    }
+}
+
+/// The kind of default configured on a `BooleanField`.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum DefaultKind {
+    /// No default is set.
+    None,
+    /// A literal `TRUE`/`FALSE` default stored in the packed state.
+    Literal(bool),
+    /// A raw SQL expression set with `with_default_expr`.
+    Expr(String),
+}
+
+/// Whether a column holds a single repeated value, e.g. to skip scans.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum ColumnSummary {
+    /// The column has no rows.
+    Empty,
+    /// Every row is true.
+    AllTrue,
+    /// Every row is false.
+    AllFalse,
+    /// Every row is NULL.
+    AllNull,
+    /// The column holds at least two different values.
+    Mixed,
+}
+
+/// The tightest constraints a sampled column satisfies, from `BooleanField::infer_constraints`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct InferredConstraints {
+    /// True if no row is NULL, so the column could be declared NOT NULL.
+    pub can_be_not_null: bool,
+    /// The most common non-NULL value; None if there is none or it is a tie.
+    pub inferred_default: Option<bool>,
+}
+
+/// How `BooleanField::merge_with` resolves two different non-NULL values or defaults.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum MergePolicy {
+    /// Fail with `BooleanFieldError::MergeConflict`, like `BooleanField::merge`.
+    Strict,
+    /// Keep the value and default of `self`.
+    PreferSelf,
+    /// Keep the value and default of `other`.
+    PreferOther,
+}
+
+/// A partial update for `BooleanField::apply_patch`, with JSON merge-patch semantics.
+///
+/// An outer `None` leaves the component unchanged; `Some(None)` sets it to NULL.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+pub struct FieldPatch {
+    /// New value
+    pub value: Option<Option<bool>>,
+    /// New literal default
+    pub default: Option<Option<bool>>,
+    /// New NOT NULL constraint
+    pub not_null: Option<bool>,
+}
+
+/// A boolean field that combines storage optimization with display configuration.
+///
+/// This type provides a high-level interface for working with boolean values
+/// while internally using a packed representation for memory efficiency.
+///
+/// # Examples
+///
+/// ```
+/// use dbform::libs::libs_fieldtype::booleanfield_dod::BooleanField;
+///
+/// // Create a field with NOT NULL constraint and default value
+/// let field = BooleanField::<&str>::new()
+///     .not_null()
+///     .default(true);
+///
+/// // When NOT NULL is set, the value is automatically set to the default
+/// assert_eq!(field.get_value(), Some(false));
+/// 
+/// // After setting a value, it will be used
 /// let mut field = field;
 /// field.set_value(Some(true)).unwrap();
 /// assert_eq!(field.get_value(), Some(true));
@@ -283,18 +1276,147 @@ pub struct BooleanField<T: fmt::Display + Clone + 'static> {
     data: PackedBooleanData,
     /// Configuration for display formatting
     display_config: Option<BooleanDisplayConfig<T>>,
+    /// Raw SQL default expression, which takes precedence over the literal default
+    default_expr: Option<String>,
+    /// Custom rules checked by `set_value`
+    validators: Vec<Arc<dyn Validator>>,
+    /// Why the value is NULL, set with `set_null_with_reason`
+    null_reason: Option<String>,
+    /// Computed labels, which take precedence over `display_config`
+    dynamic_display: Option<Arc<DynamicDisplayConfig>>,
+    /// Whether the stored column holds the negated flag, see `with_inverted_storage`
+    inverted_storage: bool,
+    /// Whether `display_bidi` wraps labels in Unicode isolates, see `with_bidi`
+    bidi: bool,
     _marker: PhantomData<T>,
 }
 
 impl<T: fmt::Display + Clone + 'static> BooleanField<T> {
+    /// CBOR simple values (major type 7) for false, true and null.
+    #[cfg(feature = "cbor")]
+    const CBOR_FALSE: u8 = 0xf4;
+    #[cfg(feature = "cbor")]
+    const CBOR_TRUE: u8 = 0xf5;
+    #[cfg(feature = "cbor")]
+    const CBOR_NULL: u8 = 0xf6;
+
     pub fn new() -> Self {
         Self {
             data: BooleanOps::new_data(),
             display_config: None,
+            default_expr: None,
+            validators: Vec::new(),
+            null_reason: None,
+            dynamic_display: None,
+            inverted_storage: false,
+            bidi: false,
             _marker: PhantomData,
         }
     }
 
+    /// Builds a field from its full state in one step.
+    ///
+    /// # Returns
+    /// `Err(BooleanFieldError::NullNotAllowed)` if `not_null` is set and the
+    /// value or default is NULL.
+    ///
+    /// # Example
+    /// ```
+    /// use dbform::libs::libs_fieldtype::booleanfield_dod::{BooleanField, BooleanFieldError};
+    ///
+    /// let field = BooleanField::<&str>::try_new(Some(false), Some(true), true).unwrap();
+    /// assert_eq!(field.get_value(), Some(false));
+    /// assert_eq!(field.data().default_value(), Some(true));
+    ///
+    /// assert_eq!(
+    ///     BooleanField::<&str>::try_new(None, Some(true), true).unwrap_err(),
+    ///     BooleanFieldError::NullNotAllowed
+    /// );
+    /// ```
+    pub fn try_new(
+        value: Option<bool>,
+        default: Option<bool>,
+        not_null: bool,
+    ) -> Result<Self, BooleanFieldError> {
+        let state = encode_state(not_null, default.into(), value.into())
+            .map_err(|_| BooleanFieldError::NullNotAllowed)?;
+        let mut field = Self::new();
+        field.data = PackedBooleanData(state);
+        Ok(field)
+    }
+
+    /// Infallible counterpart to `try_new` for test fixtures.
+    ///
+    /// # Panics
+    /// If `not_null` is set and the value or default is NULL.
+    ///
+    /// # Example
+    /// ```should_panic
+    /// use dbform::libs::libs_fieldtype::booleanfield_dod::BooleanField;
+    ///
+    /// let _ = BooleanField::<&str>::build_or_panic(None, Some(true), true);
+    /// ```
+    pub fn build_or_panic(value: Option<bool>, default: Option<bool>, not_null: bool) -> Self {
+        Self::try_new(value, default, not_null).unwrap_or_else(|err| {
+            panic!(
+                "invalid BooleanField (value: {:?}, default: {:?}, not_null: {}): {}",
+                value, default, not_null, err
+            )
+        })
+    }
+
+    /// Builds a field around a raw state byte without validating it, e.g. to
+    /// test how code copes with a corrupt stored state.
+    ///
+    /// Reads see an invalid byte as a nullable NULL field with no default;
+    /// operations that re-encode the state, such as `set_value`, panic on it.
+    ///
+    /// # Example
+    /// ```
+    /// use dbform::libs::libs_fieldtype::booleanfield_dod::BooleanField;
+    ///
+    /// // Value bits `11` are not a valid state.
+    /// let corrupt = BooleanField::<&str>::from_byte_unchecked(3);
+    /// assert_eq!(corrupt.get_value(), None);
+    /// assert_eq!(corrupt.to_int(), None);
+    /// ```
+    #[cfg(any(test, feature = "unchecked"))]
+    pub fn from_byte_unchecked(byte: u8) -> Self {
+        let mut field = Self::new();
+        field.data = PackedBooleanData::from_byte_unchecked(byte);
+        field
+    }
+
+    /// Builds a field from a state byte written by an older store, repairing
+    /// legacy patterns that are no longer valid.
+    ///
+    /// Valid bytes load unchanged. For other bytes below 32, value bits `11`
+    /// read as NULL and default bits `11` as no default; under NOT NULL a NULL
+    /// value takes the default and a NULL default takes the value.
+    ///
+    /// # Returns
+    /// `None` if the byte cannot be repaired: NOT NULL with neither a value nor
+    /// a default, or any of bits 5-7 set.
+    ///
+    /// # Example
+    /// ```
+    /// use dbform::libs::libs_fieldtype::booleanfield_dod::BooleanField;
+    ///
+    /// // Nullable, default false, value bits `11`: the value reads as NULL.
+    /// let field = BooleanField::<&str>::from_legacy_byte(3).unwrap();
+    /// assert_eq!(field.get_value(), None);
+    /// assert_eq!(field.state_code(), 2);
+    ///
+    /// assert!(BooleanField::<&str>::from_legacy_byte(0xff).is_none());
+    /// ```
+    pub fn from_legacy_byte(byte: u8) -> Option<Self> {
+        PackedBooleanData::repair_byte(byte).map(|data| {
+            let mut field = Self::new();
+            field.data = data;
+            field
+        })
+    }
+
     /// Get read-only access to the underlying boolean data
     pub fn data(&self) -> BooleanDataView<'_> {
         BooleanDataView(&self.data)
@@ -310,6 +1432,30 @@ impl<T: fmt::Display + Clone + 'static> BooleanField<T> {
         self.display_config.as_ref()
     }
 
+    /// Detaches the display configuration, leaving the field with the default rendering.
+    ///
+    /// # Example
+    /// ```
+    /// use dbform::libs::libs_fieldtype::booleanfield_dod::BooleanField;
+    ///
+    /// let mut field = BooleanField::new().with_display("No", "Yes", None);
+    /// field.set_value(Some(true)).unwrap();
+    ///
+    /// let config = field.take_display_config();
+    /// assert_eq!(field.display(), "true");
+    ///
+    /// field.set_display_config(config);
+    /// assert_eq!(field.display(), "Yes");
+    /// ```
+    pub fn take_display_config(&mut self) -> Option<BooleanDisplayConfig<T>> {
+        self.display_config.take()
+    }
+
+    /// Replaces the display configuration, e.g. to reattach one from `take_display_config`
+    pub fn set_display_config(&mut self, config: Option<BooleanDisplayConfig<T>>) {
+        self.display_config = config;
+    }
+
     pub fn with_display(
         mut self,
         false_display: T,
@@ -324,6 +1470,98 @@ impl<T: fmt::Display + Clone + 'static> BooleanField<T> {
         self
     }
 
+    /// Sets labels that are computed each time the field is displayed.
+    ///
+    /// They take precedence over the static `with_display` labels in `display`
+    /// and `toggle_label`. Clones of the field share the same closures.
+    ///
+    /// # Example
+    /// ```
+    /// use std::sync::atomic::{AtomicU32, Ordering};
+    /// use std::sync::Arc;
+    /// use dbform::libs::libs_fieldtype::booleanfield_display::DynamicDisplayConfig;
+    /// use dbform::libs::libs_fieldtype::booleanfield_dod::BooleanField;
+    ///
+    /// let year = Arc::new(AtomicU32::new(2021));
+    /// let since = Arc::clone(&year);
+    /// let field = BooleanField::<&str>::from(true).with_dynamic_display(DynamicDisplayConfig::new(
+    ///     || "No".to_string(),
+    ///     move || format!("Yes (since {})", since.load(Ordering::Relaxed)),
+    ///     || "Unknown".to_string(),
+    /// ));
+    /// assert_eq!(field.display(), "Yes (since 2021)");
+    ///
+    /// year.store(2024, Ordering::Relaxed);
+    /// assert_eq!(field.display(), "Yes (since 2024)");
+    /// ```
+    pub fn with_dynamic_display(mut self, config: DynamicDisplayConfig) -> Self {
+        self.dynamic_display = Some(Arc::new(config));
+        self
+    }
+
+    /// Marks the stored column as holding the negated flag, e.g. a legacy
+    /// `disabled` column modeled as an `enabled` field.
+    ///
+    /// The logical value from `get_value` is unchanged. Only the storage
+    /// boundary flips true and false: `to_int`, `load_int`, `to_param`,
+    /// `to_sql_value`, the DEFAULT clause and `to_sql_predicate`. NULL is
+    /// unaffected. `from_int` builds a plain field and never inverts; read a
+    /// stored integer into an inverted field with `load_int`.
+    ///
+    /// # Example
+    /// ```
+    /// use dbform::libs::libs_fieldtype::booleanfield_dod::{BooleanField, SqlDialect};
+    ///
+    /// let field = BooleanField::<&str>::from(true).default(true).with_inverted_storage();
+    /// assert_eq!(field.get_value(), Some(true));
+    /// assert_eq!(field.to_int(), Some(0));
+    /// assert_eq!(field.to_sql_value(SqlDialect::Postgres), "FALSE");
+    /// assert_eq!(field.to_sql(), "BOOLEAN DEFAULT FALSE");
+    /// assert_eq!(BooleanField::<&str>::new().with_inverted_storage().to_int(), None);
+    /// ```
+    pub fn with_inverted_storage(mut self) -> Self {
+        self.inverted_storage = true;
+        self
+    }
+
+    /// Maps a logical value to the value held in storage
+    fn stored(&self, value: bool) -> bool {
+        value != self.inverted_storage
+    }
+
+    /// Enables bidi isolation of the rendered label, see `display_bidi`.
+    /// Applies to the default labels as well as configured ones.
+    pub fn with_bidi(mut self, bidi: bool) -> Self {
+        self.bidi = bidi;
+        self
+    }
+
+    /// Same as `with_display`, but rejects configurations that fail
+    /// `BooleanDisplayConfig::validate`.
+    ///
+    /// # Example
+    /// ```
+    /// use dbform::libs::libs_fieldtype::booleanfield_dod::BooleanField;
+    ///
+    /// assert!(BooleanField::new().with_display_checked("Yes", "Yes", None).is_err());
+    /// assert!(BooleanField::new().with_display_checked("No", "Yes", None).is_ok());
+    /// ```
+    pub fn with_display_checked(
+        self,
+        false_display: T,
+        true_display: T,
+        null_display: Option<T>,
+    ) -> Result<Self, &'static str>
+    where
+        T: PartialEq,
+    {
+        let field = self.with_display(false_display, true_display, null_display);
+        if let Some(config) = field.display_config() {
+            config.validate()?;
+        }
+        Ok(field)
+    }
+
     /// Sets the NOT NULL constraint on the boolean field.
     ///
     /// # Returns
@@ -341,6 +1579,74 @@ impl<T: fmt::Display + Clone + 'static> BooleanField<T> {
         self
     }
 
+    /// Sets the NOT NULL constraint without coercing a NULL value.
+    ///
+    /// Unlike `not_null`, which silently replaces a NULL value with the default,
+    /// this fails so the missing value is noticed. A NULL default still becomes
+    /// `false`, as NOT NULL fields always carry a default.
+    ///
+    /// # Returns
+    /// `Err(BooleanFieldError::NullNotAllowed)` if the value is NULL.
+    ///
+    /// # Example
+    /// ```
+    /// use dbform::libs::libs_fieldtype::booleanfield_dod::{BooleanField, BooleanFieldError};
+    ///
+    /// let field = BooleanField::<&str>::from(true).try_not_null().unwrap();
+    /// assert!(field.is_not_null());
+    /// assert_eq!(field.get_value(), Some(true));
+    ///
+    /// let err = BooleanField::<&str>::new().try_not_null().unwrap_err();
+    /// assert_eq!(err, BooleanFieldError::NullNotAllowed);
+    /// ```
+    pub fn try_not_null(mut self) -> Result<Self, BooleanFieldError> {
+        BooleanOps::try_set_not_null(&mut self.data)?;
+        Ok(self)
+    }
+
+    /// Adds or drops the NOT NULL constraint, reporting whether it was lossy.
+    ///
+    /// Adding NOT NULL replaces a NULL value with the default and a NULL default
+    /// with `false`, as `not_null` does; dropping it never changes anything else.
+    ///
+    /// # Returns
+    /// - `Ok(true)` if the value or default changed
+    /// - `Ok(false)` if only the constraint changed, or nothing did
+    /// - `Err(BooleanFieldError::NullNotAllowed)` when adding NOT NULL to a NULL
+    ///   value with no default to fall back on; the field is left unchanged
+    ///
+    /// # Example
+    /// ```
+    /// use dbform::libs::libs_fieldtype::booleanfield_dod::{BooleanField, BooleanFieldError};
+    ///
+    /// let mut field = BooleanField::<&str>::from(true).default(false);
+    /// assert_eq!(field.set_not_null_checked(true), Ok(false));
+    /// assert_eq!(field.set_not_null_checked(false), Ok(false));
+    ///
+    /// // The default fills in for the NULL value, so the toggle is lossy.
+    /// let mut field = BooleanField::<&str>::new().default(true);
+    /// field.set_value(None).unwrap();
+    /// assert_eq!(field.set_not_null_checked(true), Ok(true));
+    /// assert_eq!(field.get_value(), Some(true));
+    ///
+    /// let mut field = BooleanField::<&str>::new();
+    /// assert_eq!(field.set_not_null_checked(true), Err(BooleanFieldError::NullNotAllowed));
+    /// assert!(!field.is_not_null());
+    /// ```
+    pub fn set_not_null_checked(&mut self, yes: bool) -> Result<bool, BooleanFieldError> {
+        let before = self.data;
+        if !yes {
+            BooleanOps::set_nullable(&mut self.data);
+            return Ok(false);
+        }
+        if before.value().is_none() && before.default_value().is_none() {
+            return Err(BooleanFieldError::NullNotAllowed);
+        }
+        BooleanOps::set_not_null(&mut self.data);
+        Ok(self.data.value() != before.value()
+            || self.data.default_value() != before.default_value())
+    }
+
     /// Gets the current value of the boolean field.
     ///
     /// # Returns
@@ -351,6 +1657,194 @@ impl<T: fmt::Display + Clone + 'static> BooleanField<T> {
         self.data().get_value()
     }
 
+    /// Reduces the state to its canonical form so equal-meaning fields share a state code.
+    ///
+    /// The canonical form is:
+    /// - NOT NULL fields are unchanged, since they must keep a default
+    /// - a nullable field whose value equals its literal default drops the default
+    /// - every other state is already canonical
+    ///
+    /// Default expressions and display configuration are not touched.
+    ///
+    /// # Example
+    /// ```
+    /// use dbform::libs::libs_fieldtype::booleanfield_dod::BooleanField;
+    ///
+    /// let mut defaulted = BooleanField::<&str>::new().default(true);
+    /// let mut explicit = BooleanField::<&str>::from(true);
+    /// assert_ne!(defaulted.state_code(), explicit.state_code());
+    ///
+    /// defaulted.canonicalize();
+    /// explicit.canonicalize();
+    /// assert_eq!(defaulted.state_code(), explicit.state_code());
+    /// ```
+    pub fn canonicalize(&mut self) {
+        BooleanOps::canonicalize(&mut self.data);
+    }
+
+    /// Returns the number of bytes used to store the value and its constraints.
+    ///
+    /// This covers the packed state only, not the display configuration or
+    /// other metadata; it is always 1.
+    ///
+    /// # Example
+    /// ```
+    /// use dbform::libs::libs_fieldtype::booleanfield_dod::BooleanField;
+    ///
+    /// assert_eq!(BooleanField::<&str>::storage_bytes(), 1);
+    /// ```
+    pub const fn storage_bytes() -> usize {
+        PackedBooleanData::SIZE
+    }
+
+    /// Returns the packed state byte, one of the 13 valid state codes unless the
+    /// field came from `from_byte_unchecked`.
+    pub fn state_code(&self) -> u8 {
+        self.data.to_byte()
+    }
+
+    /// Returns a hash of the field's definition that is stable across runs, e.g. as a schema cache key.
+    ///
+    /// Covers NOT NULL, the default, inverted storage, the display labels and
+    /// bidi isolation, hashed with FNV-1a over an explicit byte encoding rather
+    /// than std `Hash`, so the result is the same on every target and toolchain.
+    /// Like `default_kind`, a default expression replaces the literal default,
+    /// which is then not hashed. Labels are hashed through their `to_string()`
+    /// text, so `T` needs no `Hash` bound. The current value and the validators
+    /// are not included.
+    ///
+    /// # Example
+    /// ```
+    /// use dbform::libs::libs_fieldtype::booleanfield_dod::BooleanField;
+    ///
+    /// let mut a = BooleanField::new().not_null().with_display("No", "Yes", None);
+    /// let b = a.clone();
+    /// a.set_value(Some(true)).unwrap();
+    /// assert_eq!(a.config_fingerprint(), b.config_fingerprint());
+    ///
+    /// let c = BooleanField::new().not_null().with_display("Off", "On", None);
+    /// assert_ne!(a.config_fingerprint(), c.config_fingerprint());
+    /// ```
+    pub fn config_fingerprint(&self) -> u64 {
+        // One tag byte per component: 0/1 for flags and absent/present, 2 for a NULL
+        // default and 3 for a default expression, which stands in for the literal.
+        let mut hasher = Fnv1aHasher::new();
+        hasher.write(&[u8::from(self.data.not_null())]);
+        match &self.default_expr {
+            Some(expr) => {
+                hasher.write(&[3, 1]);
+                hasher.write_str(expr);
+            }
+            None => {
+                hasher.write(&[match self.data.default_value() {
+                    Some(default) => u8::from(default),
+                    None => 2,
+                }]);
+                hasher.write(&[0]);
+            }
+        }
+        hasher.write(&[u8::from(self.inverted_storage)]);
+        match &self.display_config {
+            Some(config) => {
+                hasher.write(&[1]);
+                hasher.write_str(&config.false_display.to_string());
+                hasher.write_str(&config.true_display.to_string());
+                match &config.null_display {
+                    Some(null) => {
+                        hasher.write(&[1]);
+                        hasher.write_str(&null.to_string());
+                    }
+                    None => hasher.write(&[0]),
+                }
+            }
+            None => hasher.write(&[0]),
+        }
+        hasher.write(&[u8::from(self.bidi)]);
+        hasher.finish()
+    }
+
+    /// Dumps the complete internal state as a single-line JSON object, e.g. for support bundles.
+    ///
+    /// Unlike the value-only encodings, this includes the packed state code, the
+    /// constraints and whether a display configuration or default expression is attached.
+    ///
+    /// # Example
+    /// ```
+    /// use dbform::libs::libs_fieldtype::booleanfield_dod::BooleanField;
+    ///
+    /// let field = BooleanField::<&str>::new().not_null().default(true);
+    /// assert_eq!(
+    ///     field.to_diagnostic_json(),
+    ///     r#"{"state_code":20,"value":false,"default":true,"not_null":true,"has_display_config":false,"has_default_expr":false}"#
+    /// );
+    /// ```
+    pub fn to_diagnostic_json(&self) -> String {
+        let json = |value: Option<bool>| match value {
+            Some(true) => "true",
+            Some(false) => "false",
+            None => "null",
+        };
+        format!(
+            r#"{{"state_code":{},"value":{},"default":{},"not_null":{},"has_display_config":{},"has_default_expr":{}}}"#,
+            self.state_code(),
+            json(self.data.value()),
+            json(self.data.default_value()),
+            self.data.not_null(),
+            self.display_config.is_some(),
+            self.default_expr.is_some(),
+        )
+    }
+
+    /// Asserts that the field holds `expected`, panicking with a diagnostic message otherwise.
+    ///
+    /// The message includes the state code, the `explain` sentence and the display configuration.
+    ///
+    /// # Panics
+    /// Panics if the current value differs from `expected`.
+    ///
+    /// # Example
+    /// ```
+    /// use std::panic::{self, AssertUnwindSafe};
+    /// use dbform::libs::libs_fieldtype::booleanfield_dod::BooleanField;
+    ///
+    /// let field = BooleanField::from(false).with_display("No", "Yes", None);
+    /// field.expect_value(Some(false));
+    ///
+    /// let payload = panic::catch_unwind(AssertUnwindSafe(|| field.expect_value(Some(true)))).unwrap_err();
+    /// assert_eq!(
+    ///     payload.downcast_ref::<String>().unwrap(),
+    ///     "expected value Some(true), found Some(false) \
+    ///      (state code 8: value is false; display: false=\"No\", true=\"Yes\", null=none)"
+    /// );
+    /// ```
+    #[track_caller]
+    pub fn expect_value(&self, expected: Option<bool>) {
+        let actual = self.data.value();
+        if actual == expected {
+            return;
+        }
+        let display = match &self.display_config {
+            Some(config) => format!(
+                "false=\"{}\", true=\"{}\", null={}",
+                config.false_display,
+                config.true_display,
+                config
+                    .null_display
+                    .as_ref()
+                    .map_or_else(|| "none".to_string(), |null| format!("\"{}\"", null))
+            ),
+            None => "none".to_string(),
+        };
+        panic!(
+            "expected value {:?}, found {:?} (state code {}: {}; display: {})",
+            expected,
+            actual,
+            self.state_code(),
+            self.explain(),
+            display
+        );
+    }
+
     /// Checks if the field has the NOT NULL constraint set.
     ///
     /// # Returns
@@ -377,28 +1871,286 @@ impl<T: fmt::Display + Clone + 'static> BooleanField<T> {
     /// ```
     pub fn default(mut self, default: bool) -> Self {
         BooleanOps::set_default(&mut self.data, default);
+        self.default_expr = None;
         self
     }
 
-    /// Sets a new value for the boolean field with validation.
-    ///
-    /// # Arguments
-    /// * `value` - The value to set (`Some(true)`, `Some(false)`, or `None` for NULL)
+    /// Sets a raw SQL expression as the column default, e.g. `now() IS NOT NULL`.
     ///
-    /// # Returns
-    /// - `Ok(())` if the value was set successfully
-    /// - `Err(String)` if the value violates any constraints (e.g., setting NULL on a NOT NULL field)
+    /// `to_sql` emits the expression verbatim as `DEFAULT (expr)`. The expression
+    /// replaces any literal default set with `default`, and calling `default`
+    /// afterwards clears the expression again. A NOT NULL field keeps its
+    /// literal default internally, since the packed state requires one, but the
+    /// expression is what gets rendered.
     ///
     /// # Example
     /// ```
     /// use dbform::libs::libs_fieldtype::booleanfield_dod::BooleanField;
     ///
-    /// let mut field = BooleanField::<&str>::new();
+    /// let field = BooleanField::<&str>::new()
+    ///     .default(true)
+    ///     .with_default_expr("is_admin()".to_string());
+    /// assert_eq!(field.to_sql(), "BOOLEAN DEFAULT (is_admin())");
+    /// ```
+    pub fn with_default_expr(mut self, expr: String) -> Self {
+        // Only nullable fields can drop the literal; NOT NULL fields keep it as a fallback.
+        let _ = BooleanOps::clear_default(&mut self.data);
+        self.default_expr = Some(expr);
+        self
+    }
+
+    /// Copies the default of `other`, literal or expression, into this field.
+    ///
+    /// This behaves like calling `default` or `with_default_expr` with the other
+    /// field's default, so a NULL value takes on a copied literal. If `other`
+    /// has no default, this field's default is cleared; a NOT NULL field keeps
+    /// its literal default, since the packed state requires one.
+    ///
+    /// # Example
+    /// ```
+    /// use dbform::libs::libs_fieldtype::booleanfield_dod::{BooleanField, DefaultKind};
+    ///
+    /// let source = BooleanField::<&str>::new().default(true);
+    /// let field = BooleanField::<&str>::new().with_default_from(&source);
+    /// assert_eq!(field.default_kind(), DefaultKind::Literal(true));
+    ///
+    /// let source = BooleanField::<&str>::new().with_default_expr("is_admin()".to_string());
+    /// let field = BooleanField::<&str>::new().default(false).with_default_from(&source);
+    /// assert_eq!(field.default_kind(), DefaultKind::Expr("is_admin()".to_string()));
+    ///
+    /// let field = field.with_default_from(&BooleanField::new());
+    /// assert_eq!(field.default_kind(), DefaultKind::None);
+    /// ```
+    pub fn with_default_from(mut self, other: &Self) -> Self {
+        match other.data.default_value() {
+            Some(default) => BooleanOps::set_default(&mut self.data, default),
+            // Only nullable fields can drop the literal; NOT NULL fields keep it as a fallback.
+            None => {
+                let _ = BooleanOps::clear_default(&mut self.data);
+            }
+        }
+        self.default_expr = other.default_expr.clone();
+        self
+    }
+
+    /// Sets a new value for the boolean field with validation.
+    ///
+    /// # Arguments
+    /// * `value` - The value to set (`Some(true)`, `Some(false)`, or `None` for NULL)
+    ///
+    /// # Returns
+    /// - `Ok(())` if the value was set successfully
+    /// - `Err(String)` if the value violates any constraints (e.g., setting NULL on a NOT NULL field)
+    ///
+    /// # Example
+    /// ```
+    /// use dbform::libs::libs_fieldtype::booleanfield_dod::BooleanField;
+    ///
+    /// let mut field = BooleanField::<&str>::new();
     /// assert!(field.set_value(Some(true)).is_ok());
     /// assert_eq!(field.get_value(), Some(true));
     /// ```
     pub fn set_value(&mut self, value: Option<bool>) -> Result<(), String> {
-        BooleanOps::set_value(&mut self.data, value)
+        let mut data = self.data;
+        BooleanOps::set_value(&mut data, value)?;
+        for validator in &self.validators {
+            validator.validate(value)?;
+        }
+        self.data = data;
+        self.null_reason = None;
+        Ok(())
+    }
+
+    /// Sets the value to NULL and records why, e.g. `"redacted"` or `"not provided"`.
+    ///
+    /// The reason is metadata kept outside the packed byte. Any later
+    /// `set_value` drops it, and it is only reported while the value is NULL.
+    ///
+    /// # Returns
+    /// The same errors as `set_value(None)`; the reason is not stored on error.
+    ///
+    /// # Example
+    /// ```
+    /// use dbform::libs::libs_fieldtype::booleanfield_dod::BooleanField;
+    ///
+    /// let mut field = BooleanField::<&str>::from(true);
+    /// field.set_null_with_reason("redacted").unwrap();
+    /// assert_eq!(field.null_reason(), Some("redacted"));
+    /// assert_eq!(field.explain(), "value is NULL (redacted)");
+    ///
+    /// field.set_value(Some(false)).unwrap();
+    /// assert_eq!(field.null_reason(), None);
+    ///
+    /// let mut required = BooleanField::<&str>::new().not_null();
+    /// assert!(required.set_null_with_reason("not provided").is_err());
+    /// ```
+    pub fn set_null_with_reason(&mut self, reason: impl Into<String>) -> Result<(), String> {
+        self.set_value(None)?;
+        self.null_reason = Some(reason.into());
+        Ok(())
+    }
+
+    /// Returns the reason recorded by `set_null_with_reason`, if the value is still NULL
+    pub fn null_reason(&self) -> Option<&str> {
+        self.null_reason
+            .as_deref()
+            .filter(|_| self.data.value().is_none())
+    }
+
+    /// Collects every constraint `value` would violate, without changing the field.
+    ///
+    /// # Returns
+    /// An empty vector if `set_value(value)` would succeed; otherwise
+    /// `BooleanFieldError::NullNotAllowed` for the NOT NULL constraint followed by
+    /// one `BooleanFieldError::Validation` per rejecting validator.
+    ///
+    /// # Example
+    /// ```
+    /// use dbform::libs::libs_fieldtype::booleanfield_dod::{BooleanField, BooleanFieldError};
+    ///
+    /// let field = BooleanField::<&str>::new()
+    ///     .not_null()
+    ///     .with_validator(|value: Option<bool>| match value {
+    ///         Some(true) => Ok(()),
+    ///         _ => Err("Must be accepted".to_string()),
+    ///     });
+    ///
+    /// assert_eq!(
+    ///     field.check_value(None),
+    ///     [
+    ///         BooleanFieldError::NullNotAllowed,
+    ///         BooleanFieldError::Validation("Must be accepted".to_string()),
+    ///     ]
+    /// );
+    /// assert!(field.check_value(Some(true)).is_empty());
+    /// ```
+    pub fn check_value(&self, value: Option<bool>) -> Vec<BooleanFieldError> {
+        let mut errors = Vec::new();
+        if self.data.not_null() && value.is_none() {
+            errors.push(BooleanFieldError::NullNotAllowed);
+        }
+        errors.extend(
+            self.validators
+                .iter()
+                .filter_map(|validator| validator.validate(value).err())
+                .map(BooleanFieldError::Validation),
+        );
+        errors
+    }
+
+    /// Adds a custom rule that `set_value` checks after the NOT NULL constraint.
+    ///
+    /// Validators only guard `set_value`; builders and logical operations do not run them.
+    ///
+    /// # Example
+    /// ```
+    /// use dbform::libs::libs_fieldtype::booleanfield_dod::BooleanField;
+    ///
+    /// let mut field = BooleanField::<&str>::new().with_validator(|value: Option<bool>| {
+    ///     if value == Some(true) {
+    ///         Err("Flag cannot be enabled".to_string())
+    ///     } else {
+    ///         Ok(())
+    ///     }
+    /// });
+    /// assert!(field.set_value(Some(true)).is_err());
+    /// assert_eq!(field.get_value(), None);
+    /// assert!(field.set_value(Some(false)).is_ok());
+    /// ```
+    pub fn with_validator(mut self, validator: impl Validator + 'static) -> Self {
+        self.validators.push(Arc::new(validator));
+        self
+    }
+
+    /// Applies the components present in `patch`, all at once or not at all.
+    ///
+    /// The resulting combination is checked as a whole, and nothing is coerced:
+    /// unlike `not_null`, a patch that leaves a NOT NULL field with a NULL value
+    /// or default is rejected. A patched value is also checked by the validators.
+    /// Patching the default replaces any default expression.
+    ///
+    /// # Returns
+    /// - `Err(BooleanFieldError::NullNotAllowed)` if the field would be NOT NULL
+    ///   with a NULL value or default
+    /// - `Err(BooleanFieldError::Validation)` if a validator rejects the patched value
+    ///
+    /// The field is left unchanged on error.
+    ///
+    /// # Example
+    /// ```
+    /// use dbform::libs::libs_fieldtype::booleanfield_dod::{BooleanField, BooleanFieldError, FieldPatch};
+    ///
+    /// let mut field = BooleanField::<&str>::new();
+    /// field
+    ///     .apply_patch(FieldPatch { value: Some(Some(true)), ..FieldPatch::default() })
+    ///     .unwrap();
+    /// assert_eq!(field.get_value(), Some(true));
+    ///
+    /// let patch = FieldPatch { not_null: Some(true), default: Some(Some(false)), value: None };
+    /// field.apply_patch(patch).unwrap();
+    /// assert!(field.is_not_null());
+    ///
+    /// let patch = FieldPatch { value: Some(None), default: Some(Some(true)), not_null: None };
+    /// assert_eq!(field.apply_patch(patch), Err(BooleanFieldError::NullNotAllowed));
+    /// assert_eq!(field.get_value(), Some(true));
+    /// assert_eq!(field.data().default_value(), Some(false));
+    /// ```
+    pub fn apply_patch(&mut self, patch: FieldPatch) -> Result<(), BooleanFieldError> {
+        let not_null = patch.not_null.unwrap_or(self.data.not_null());
+        let default = patch.default.unwrap_or(self.data.default_value());
+        let value = patch.value.unwrap_or(self.data.value());
+
+        if not_null && (default.is_none() || value.is_none()) {
+            return Err(BooleanFieldError::NullNotAllowed);
+        }
+        if patch.value.is_some() {
+            for validator in &self.validators {
+                validator
+                    .validate(value)
+                    .map_err(BooleanFieldError::Validation)?;
+            }
+        }
+
+        // Every combination without a NULL under NOT NULL is one of the valid states.
+        self.data =
+            PackedBooleanData(encode_state(not_null, default.into(), value.into()).unwrap());
+        if patch.default.is_some() {
+            self.default_expr = None;
+        }
+        if patch.value.is_some() {
+            self.null_reason = None;
+        }
+        Ok(())
+    }
+
+    /// Restricts the field to the values in `allowed`, e.g. only true or NULL.
+    ///
+    /// The restriction is installed as a validator, so later `set_value` calls
+    /// reject any value outside the set. NOT NULL still applies on top of it.
+    ///
+    /// # Returns
+    /// `Err(BooleanFieldError::Validation)` if the current value is not in `allowed`;
+    /// the restriction is not installed in that case.
+    ///
+    /// # Example
+    /// ```
+    /// use dbform::libs::libs_fieldtype::booleanfield_dod::BooleanField;
+    ///
+    /// let mut field = BooleanField::<&str>::from(true);
+    /// field.restrict(&[Some(true), None]).unwrap();
+    /// assert!(field.set_value(Some(false)).is_err());
+    /// assert!(field.set_value(None).is_ok());
+    ///
+    /// let mut field = BooleanField::<&str>::from(false);
+    /// assert!(field.restrict(&[Some(true)]).is_err());
+    /// ```
+    pub fn restrict(&mut self, allowed: &[Option<bool>]) -> Result<(), BooleanFieldError> {
+        let validator = AllowedValues::new(allowed);
+        validator
+            .validate(self.data.value())
+            .map_err(BooleanFieldError::Validation)?;
+        self.validators.push(Arc::new(validator));
+        Ok(())
     }
 
     /// Performs a logical AND operation with another boolean field.
@@ -408,6 +2160,7 @@ impl<T: fmt::Display + Clone + 'static> BooleanField<T> {
     ///
     /// # Returns
     /// A new `BooleanField` that is the result of the AND operation.
+    /// If `self` is NOT NULL but the result is NULL, the result is nullable.
     ///
     /// # Truth Table
     /// 
@@ -422,11 +2175,21 @@ impl<T: fmt::Display + Clone + 'static> BooleanField<T> {
     /// | NULL  | true  | NULL    |
     /// | NULL  | false | false   |
     /// | NULL  | NULL  | NULL    |
+    ///
+    /// # Example
+    /// ```
+    /// use dbform::libs::libs_fieldtype::booleanfield_dod::BooleanField;
+    ///
+    /// let mut required = BooleanField::<&str>::new().not_null();
+    /// required.set_value(Some(true)).unwrap();
+    /// let result = required.and(BooleanField::new());
+    /// assert_eq!(result.get_value(), None);
+    /// assert!(!result.is_not_null());
+    /// ```
     pub fn and(self, other: Self) -> Self {
         Self {
             data: BooleanOps::and(&self.data, &other.data),
-            display_config: self.display_config,
-            _marker: PhantomData,
+            ..self
         }
     }
 
@@ -437,6 +2200,7 @@ impl<T: fmt::Display + Clone + 'static> BooleanField<T> {
     ///
     /// # Returns
     /// A new `BooleanField` that is the result of the OR operation.
+    /// If `self` is NOT NULL but the result is NULL, the result is nullable.
     ///
     /// # Truth Table
     /// 
@@ -466,8 +2230,44 @@ impl<T: fmt::Display + Clone + 'static> BooleanField<T> {
     pub fn or(self, other: Self) -> Self {
         Self {
             data: BooleanOps::or(&self.data, &other.data),
-            display_config: self.display_config,
-            _marker: PhantomData,
+            ..self
+        }
+    }
+
+    /// Performs a logical XOR operation with another boolean field.
+    ///
+    /// # Arguments
+    /// * `other` - The other boolean field to XOR with
+    ///
+    /// # Returns
+    /// A new `BooleanField` that is the result of the XOR operation.
+    /// If `self` is NOT NULL but the result is NULL, the result is nullable.
+    ///
+    /// # Truth Table
+    ///
+    /// | A     | B     | A XOR B |
+    /// |-------|-------|---------|
+    /// | true  | true  | false   |
+    /// | true  | false | true    |
+    /// | true  | NULL  | NULL    |
+    /// | false | true  | true    |
+    /// | false | false | false   |
+    /// | false | NULL  | NULL    |
+    /// | NULL  | true  | NULL    |
+    /// | NULL  | false | NULL    |
+    /// | NULL  | NULL  | NULL    |
+    ///
+    /// # Example
+    /// ```
+    /// use dbform::libs::libs_fieldtype::booleanfield_dod::BooleanField;
+    ///
+    /// let result = BooleanField::<&str>::from(true).xor(BooleanField::from(false));
+    /// assert_eq!(result.get_value(), Some(true));
+    /// ```
+    pub fn xor(self, other: Self) -> Self {
+        Self {
+            data: BooleanOps::xor(&self.data, &other.data),
+            ..self
         }
     }
 
@@ -496,8 +2296,7 @@ impl<T: fmt::Display + Clone + 'static> BooleanField<T> {
     pub fn not(self) -> Self {
         Self {
             data: BooleanOps::not(&self.data),
-            display_config: self.display_config,
-            _marker: PhantomData,
+            ..self
         }
     }
 
@@ -550,9 +2349,261 @@ impl<T: fmt::Display + Clone + 'static> BooleanField<T> {
     /// assert_eq!(field.display(), "NULL");
     /// ```
     pub fn display(&self) -> String {
+        self.label_for(self.data.value())
+    }
+
+    /// Same as `display`, but wraps the label in Unicode isolate marks
+    /// (U+2066 LEFT-TO-RIGHT ISOLATE ... U+2069 POP DIRECTIONAL ISOLATE) when
+    /// enabled with `with_bidi`.
+    ///
+    /// Isolating right-to-left labels keeps them from reordering the
+    /// surrounding left-to-right text on mixed-direction lines.
+    ///
+    /// # Example
+    /// ```
+    /// use dbform::libs::libs_fieldtype::booleanfield_dod::BooleanField;
+    ///
+    /// let field = BooleanField::from(true).with_display("לא", "כן", None);
+    /// assert_eq!(field.display_bidi(), "כן");
+    ///
+    /// let field = field.with_bidi(true);
+    /// assert_eq!(field.display_bidi(), "\u{2066}כן\u{2069}");
+    ///
+    /// let plain = BooleanField::<&str>::new().with_bidi(true);
+    /// assert_eq!(plain.display_bidi(), "\u{2066}NULL\u{2069}");
+    /// ```
+    pub fn display_bidi(&self) -> String {
+        let label = self.display();
+        if self.bidi {
+            format!("\u{2066}{label}\u{2069}")
+        } else {
+            label
+        }
+    }
+
+    /// Renders the value with a one-off `config`, ignoring the field's own display
+    /// configuration and any dynamic labels.
+    ///
+    /// # Example
+    /// ```
+    /// use dbform::libs::libs_fieldtype::booleanfield_display::BooleanDisplayConfig;
+    /// use dbform::libs::libs_fieldtype::booleanfield_dod::BooleanField;
+    ///
+    /// let field = BooleanField::from(true).with_display("No", "Yes", None);
+    /// let on_off = BooleanDisplayConfig { false_display: "Off", true_display: "On", null_display: None };
+    /// let check = BooleanDisplayConfig { false_display: "✗", true_display: "✓", null_display: None };
+    ///
+    /// assert_eq!(field.display_with(&on_off), "On");
+    /// assert_eq!(field.display_with(&check), "✓");
+    /// assert_eq!(field.display(), "Yes");
+    /// ```
+    pub fn display_with(&self, config: &BooleanDisplayConfig<T>) -> String {
+        BooleanDisplay::new(self.data.value(), config).display_value()
+    }
+
+    /// Collects the distinct strings `display` renders across `fields`, e.g. for a legend.
+    ///
+    /// Each field contributes the label for its current value under its own
+    /// display configuration, so labels no field currently uses are left out.
+    ///
+    /// # Example
+    /// ```
+    /// use dbform::libs::libs_fieldtype::booleanfield_dod::BooleanField;
+    ///
+    /// let fields = [
+    ///     BooleanField::from(true).with_display("No", "Yes", None),
+    ///     BooleanField::from(true).with_display("Off", "On", None),
+    ///     BooleanField::new().with_display("No", "Yes", Some("Unknown")),
+    ///     BooleanField::from(true).with_display("No", "Yes", None),
+    /// ];
+    /// let strings = BooleanField::collect_display_strings(&fields);
+    /// assert_eq!(strings.into_iter().collect::<Vec<_>>(), ["On", "Unknown", "Yes"]);
+    /// ```
+    pub fn collect_display_strings(fields: &[Self]) -> BTreeSet<String> {
+        fields.iter().map(Self::display).collect()
+    }
+
+    /// Returns the display configuration shared by every field in `fields`,
+    /// e.g. to check whether a column can be exported with a single legend.
+    ///
+    /// # Returns
+    /// - `Some(config)` if all fields carry an equal configuration
+    /// - `None` for an empty slice, if any field has no configuration, or if two differ
+    ///
+    /// # Example
+    /// ```
+    /// use dbform::libs::libs_fieldtype::booleanfield_dod::BooleanField;
+    ///
+    /// let uniform = [
+    ///     BooleanField::from(true).with_display("No", "Yes", None),
+    ///     BooleanField::new().with_display("No", "Yes", None),
+    /// ];
+    /// let config = BooleanField::common_display_config(&uniform).unwrap();
+    /// assert_eq!(config.true_display, "Yes");
+    ///
+    /// let mixed = [
+    ///     BooleanField::from(true).with_display("No", "Yes", None),
+    ///     BooleanField::from(true).with_display("Off", "On", None),
+    /// ];
+    /// assert!(BooleanField::common_display_config(&mixed).is_none());
+    /// ```
+    pub fn common_display_config(fields: &[Self]) -> Option<BooleanDisplayConfig<T>>
+    where
+        T: PartialEq,
+    {
+        let (first, rest) = fields.split_first()?;
+        let config = first.display_config.as_ref()?;
+        rest.iter()
+            .all(|field| field.display_config.as_ref() == Some(config))
+            .then(|| config.clone())
+    }
+
+    /// Renders the value as a checkbox indicator with the default `IndicatorConfig`
+    /// (`"[x]"`, `"[ ]"`, `"[-]"`).
+    ///
+    /// # Example
+    /// ```
+    /// use dbform::libs::libs_fieldtype::booleanfield_dod::BooleanField;
+    /// use dbform::libs::libs_fieldtype::booleanfield_display::IndicatorConfig;
+    ///
+    /// assert_eq!(BooleanField::<&str>::from(true).to_indicator(), "[x]");
+    /// assert_eq!(BooleanField::<&str>::from(false).to_indicator(), "[ ]");
+    /// assert_eq!(BooleanField::<&str>::new().to_indicator(), "[-]");
+    ///
+    /// let config = IndicatorConfig { true_indicator: "(*)", false_indicator: "( )", null_indicator: "(?)" };
+    /// assert_eq!(BooleanField::<&str>::new().to_indicator_with(&config), "(?)");
+    /// ```
+    pub fn to_indicator(&self) -> &'static str {
+        self.to_indicator_with(&IndicatorConfig::default())
+    }
+
+    /// Renders the value as an indicator from `config`
+    pub fn to_indicator_with(&self, config: &IndicatorConfig) -> &'static str {
+        config.indicator_for(self.data.value())
+    }
+
+    /// Renders the value as a single character with the default `CompactDisplayConfig`
+    /// (`'✓'`, `'✗'`, `' '`).
+    ///
+    /// # Example
+    /// ```
+    /// use dbform::libs::libs_fieldtype::booleanfield_dod::BooleanField;
+    /// use dbform::libs::libs_fieldtype::booleanfield_display::CompactDisplayConfig;
+    ///
+    /// assert_eq!(BooleanField::<&str>::from(true).to_compact(), '✓');
+    /// assert_eq!(BooleanField::<&str>::new().to_compact(), ' ');
+    ///
+    /// let config = CompactDisplayConfig { true_char: 'T', false_char: 'F', null_char: '␀' };
+    /// assert_eq!(BooleanField::<&str>::from(false).to_compact_with(&config), 'F');
+    /// ```
+    pub fn to_compact(&self) -> char {
+        self.to_compact_with(&CompactDisplayConfig::default())
+    }
+
+    /// Renders the value as a single character from `config`
+    pub fn to_compact_with(&self, config: &CompactDisplayConfig) -> char {
+        config.char_for(self.data.value())
+    }
+
+    /// Renders the value as a TOML key/value line such as `active = true`.
+    ///
+    /// TOML has no null, so a NULL value returns `None` and the key should be
+    /// omitted. Keys that are not valid bare keys are written as quoted keys.
+    ///
+    /// # Example
+    /// ```
+    /// use dbform::libs::libs_fieldtype::booleanfield_dod::BooleanField;
+    ///
+    /// assert_eq!(BooleanField::<&str>::from(true).to_toml_fragment("active").unwrap(), "active = true");
+    /// assert_eq!(BooleanField::<&str>::from(false).to_toml_fragment("dark mode").unwrap(), r#""dark mode" = false"#);
+    /// assert_eq!(BooleanField::<&str>::new().to_toml_fragment("active"), None);
+    /// ```
+    pub fn to_toml_fragment(&self, key: &str) -> Option<String> {
+        let value = self.data.value()?;
+        let is_bare = !key.is_empty()
+            && key
+                .chars()
+                .all(|c| c.is_ascii_alphanumeric() || c == '_' || c == '-');
+        let key = if is_bare {
+            key.to_string()
+        } else {
+            let mut quoted = String::from('"');
+            for c in key.chars() {
+                match c {
+                    '"' => quoted.push_str("\\\""),
+                    '\\' => quoted.push_str("\\\\"),
+                    c if c.is_control() => quoted.push_str(&format!("\\u{:04X}", c as u32)),
+                    c => quoted.push(c),
+                }
+            }
+            quoted.push('"');
+            quoted
+        };
+        Some(format!("{} = {}", key, value))
+    }
+
+    /// Renders the value as the content of a Markdown table cell.
+    ///
+    /// Uses the same labels as `display`, escaping `|` as `\|` so custom labels
+    /// cannot split the cell. NULL renders as the dynamic NULL label, the
+    /// configured `null_display` (e.g. `"-"`), or an empty cell when neither is set.
+    ///
+    /// # Example
+    /// ```
+    /// use dbform::libs::libs_fieldtype::booleanfield_dod::BooleanField;
+    ///
+    /// let mut field = BooleanField::new().with_display("no", "yes|confirmed", None);
+    /// assert_eq!(field.to_markdown_cell(), "");
+    ///
+    /// field.set_value(Some(true)).unwrap();
+    /// assert_eq!(field.to_markdown_cell(), "yes\\|confirmed");
+    /// ```
+    pub fn to_markdown_cell(&self) -> String {
+        let label = match (
+            self.data.value(),
+            &self.dynamic_display,
+            self.display_config.as_ref(),
+        ) {
+            (
+                None,
+                None,
+                Some(BooleanDisplayConfig {
+                    null_display: Some(null_display),
+                    ..
+                }),
+            ) => null_display.to_string(),
+            (None, None, _) => String::new(),
+            (value, _, _) => self.label_for(value),
+        };
+        label.replace('|', "\\|")
+    }
+
+    /// Returns the label for the opposite value, e.g. for a toggle button.
+    ///
+    /// For a NULL field this is the true label, since toggling an unset
+    /// checkbox checks it.
+    ///
+    /// # Example
+    /// ```
+    /// use dbform::libs::libs_fieldtype::booleanfield_dod::BooleanField;
+    ///
+    /// let mut field = BooleanField::new().with_display("Off", "On", None);
+    /// assert_eq!(field.toggle_label(), "On");
+    ///
+    /// field.set_value(Some(true)).unwrap();
+    /// assert_eq!(field.toggle_label(), "Off");
+    /// ```
+    pub fn toggle_label(&self) -> String {
+        self.label_for(Some(!self.data.value().unwrap_or(false)))
+    }
+
+    /// Renders `value` with the display configuration or the default strings
+    fn label_for(&self, value: Option<bool>) -> String {
+        if let Some(dynamic) = &self.dynamic_display {
+            return dynamic.label_for(value);
+        }
         match self.display_config.as_ref() {
             Some(config) => {
-                let value = self.data.value();
                 match value {
                     Some(true) => config.true_display.to_string(),
                     Some(false) => config.false_display.to_string(),
@@ -562,7 +2613,7 @@ impl<T: fmt::Display + Clone + 'static> BooleanField<T> {
                     ),
                 }
             }
-            None => match self.data.value() {
+            None => match value {
                 Some(true) => "true".to_string(),
                 Some(false) => "false".to_string(),
                 None => "NULL".to_string(),
@@ -570,48 +2621,3541 @@ impl<T: fmt::Display + Clone + 'static> BooleanField<T> {
         }
     }
 
-    /// Returns the SQL type definition as a string with all constraints
-    pub fn to_sql(&self) -> String {
-        BooleanOps::to_sql(&self.data)
+    /// Compares two values with SQL semantics, where `false < true`.
+    ///
+    /// # Returns
+    /// - `None` if either value is NULL, as the comparison is unknown
+    /// - `Some(Ordering)` otherwise
+    ///
+    /// # Example
+    /// ```
+    /// use std::cmp::Ordering;
+    /// use dbform::libs::libs_fieldtype::booleanfield_dod::BooleanField;
+    ///
+    /// let f = BooleanField::<&str>::from(false);
+    /// let t = BooleanField::<&str>::from(true);
+    /// assert_eq!(f.sql_compare(&t), Some(Ordering::Less));
+    /// assert_eq!(t.sql_compare(&BooleanField::new()), None);
+    /// ```
+    pub fn sql_compare(&self, other: &Self) -> Option<Ordering> {
+        match (self.data.value(), other.data.value()) {
+            (Some(a), Some(b)) => Some(a.cmp(&b)),
+            _ => None,
+        }
     }
-}
+
+    /// Returns the value as a prepared-statement parameter.
+    ///
+    /// Binding a `BooleanParam` avoids interpolating literals into SQL. With the
+    /// `rusqlite` or `tokio-postgres` features it can be passed to those drivers
+    /// directly; otherwise convert it to `Option<bool>`. The parameter holds the
+    /// stored value, so `with_inverted_storage` flips it like `to_sql_value`.
+    ///
+    /// # Example
+    /// ```
+    /// use dbform::libs::libs_fieldtype::booleanfield_dod::{BooleanField, BooleanParam};
+    ///
+    /// assert_eq!(BooleanField::<&str>::from(true).to_param(), BooleanParam::True);
+    /// assert_eq!(BooleanField::<&str>::from(false).to_param(), BooleanParam::False);
+    /// assert_eq!(BooleanField::<&str>::new().to_param(), BooleanParam::Null);
+    ///
+    /// let bound: Option<bool> = BooleanParam::Null.into();
+    /// assert_eq!(bound, None);
+    /// ```
+    pub fn to_param(&self) -> BooleanParam {
+        self.data.value().map(|value| self.stored(value)).into()
+    }
+
+    /// Returns the truth table of a logical operation as (a, b, result) rows.
+    ///
+    /// Rows follow the order of the documented tables: `a` and `b` each run
+    /// through true, false, NULL.
+    ///
+    /// # Example
+    /// ```
+    /// use dbform::libs::libs_fieldtype::booleanfield_dod::{BinaryOp, BooleanField};
+    ///
+    /// let table = BooleanField::<&str>::truth_table(BinaryOp::And);
+    /// assert_eq!(
+    ///     table.map(|(_, _, result)| result),
+    ///     [
+    ///         Some(true), Some(false), None,
+    ///         Some(false), Some(false), Some(false),
+    ///         None, Some(false), None,
+    ///     ]
+    /// );
+    /// ```
+    pub fn truth_table(op: BinaryOp) -> [(Option<bool>, Option<bool>, Option<bool>); 9] {
+        BooleanOps::truth_table(op)
+    }
+
+    /// Treats the field as a success flag.
+    ///
+    /// # Returns
+    /// - `Ok(())` if the value is `true`
+    /// - `Err(err)` if the value is `false` or NULL
+    ///
+    /// NULL fails like `false`, matching how a SQL `WHERE` clause only passes
+    /// rows that are definitely true. Match on `decide()` to handle NULL separately.
+    ///
+    /// # Example
+    /// ```
+    /// use dbform::libs::libs_fieldtype::booleanfield_dod::BooleanField;
+    ///
+    /// assert_eq!(BooleanField::<&str>::from(true).to_result("disabled"), Ok(()));
+    /// assert_eq!(BooleanField::<&str>::from(false).to_result("disabled"), Err("disabled"));
+    /// assert_eq!(BooleanField::<&str>::new().to_result("disabled"), Err("disabled"));
+    /// ```
+    pub fn to_result<E>(&self, err: E) -> Result<(), E> {
+        match self.data.value() {
+            Some(true) => Ok(()),
+            Some(false) | None => Err(err),
+        }
+    }
+
+    /// Returns the value as a `Decision`, mapping NULL to `Decision::Unknown`.
+    ///
+    /// # Example
+    /// ```
+    /// use dbform::libs::libs_fieldtype::booleanfield_dod::{BooleanField, Decision};
+    ///
+    /// assert_eq!(BooleanField::<&str>::from(true).decide(), Decision::True);
+    /// assert_eq!(BooleanField::<&str>::from(false).decide(), Decision::False);
+    /// assert_eq!(BooleanField::<&str>::new().decide(), Decision::Unknown);
+    /// ```
+    pub fn decide(&self) -> Decision {
+        self.data.value().into()
+    }
+
+    /// Three-state AND over a slice: `False` if any field is false, otherwise
+    /// `Unknown` if any is NULL, otherwise `True` (including for an empty slice).
+    ///
+    /// # Example
+    /// ```
+    /// use dbform::libs::libs_fieldtype::booleanfield_dod::{BooleanField, Decision};
+    ///
+    /// let fields = [BooleanField::<&str>::from(true), BooleanField::new()];
+    /// assert_eq!(BooleanField::all_decide(&fields), Decision::Unknown);
+    /// assert_eq!(BooleanField::any_decide(&fields), Decision::True);
+    /// ```
+    pub fn all_decide(fields: &[Self]) -> Decision {
+        let mut decision = Decision::True;
+        for field in fields {
+            match field.decide() {
+                Decision::False => return Decision::False,
+                Decision::Unknown => decision = Decision::Unknown,
+                Decision::True => {}
+            }
+        }
+        decision
+    }
+
+    /// Three-state OR over a slice: `True` if any field is true, otherwise
+    /// `Unknown` if any is NULL, otherwise `False` (including for an empty slice).
+    pub fn any_decide(fields: &[Self]) -> Decision {
+        let mut decision = Decision::False;
+        for field in fields {
+            match field.decide() {
+                Decision::True => return Decision::True,
+                Decision::Unknown => decision = Decision::Unknown,
+                Decision::False => {}
+            }
+        }
+        decision
+    }
+
+    /// Keeps this field's value, falling back to `other`'s when this one is NULL.
+    ///
+    /// Only the value is taken from `other`; constraints and display
+    /// configuration always come from `self`. The fallback goes through
+    /// `set_value`, so if one of this field's validators (e.g. from `restrict`)
+    /// rejects it, the field stays NULL.
+    ///
+    /// # Example
+    /// ```
+    /// use dbform::libs::libs_fieldtype::booleanfield_dod::BooleanField;
+    ///
+    /// let fused = BooleanField::<&str>::new().prefer(BooleanField::from(true));
+    /// assert_eq!(fused.get_value(), Some(true));
+    ///
+    /// let fused = BooleanField::<&str>::from(false).prefer(BooleanField::from(true));
+    /// assert_eq!(fused.get_value(), Some(false));
+    ///
+    /// let fused = BooleanField::<&str>::from(false).prefer(BooleanField::new());
+    /// assert_eq!(fused.get_value(), Some(false));
+    ///
+    /// let fused = BooleanField::<&str>::new().prefer(BooleanField::new());
+    /// assert_eq!(fused.get_value(), None);
+    ///
+    /// let mut only_true = BooleanField::<&str>::new();
+    /// only_true.restrict(&[Some(true), None]).unwrap();
+    /// assert_eq!(only_true.prefer(BooleanField::from(false)).get_value(), None);
+    /// ```
+    pub fn prefer(mut self, other: Self) -> Self {
+        if let (None, Some(value)) = (self.data.value(), other.data.value()) {
+            // A rejected fallback leaves the field NULL.
+            let _ = self.set_value(Some(value));
+        }
+        self
+    }
+
+    /// Checks whether the constraints of two fields can coexist.
+    ///
+    /// # Returns
+    /// `false` if either field is NOT NULL while the other has a NULL value or no default.
+    ///
+    /// # Example
+    /// ```
+    /// use dbform::libs::libs_fieldtype::booleanfield_dod::BooleanField;
+    ///
+    /// let required = BooleanField::<&str>::new().not_null();
+    /// assert!(!required.constraints_compatible(&BooleanField::new()));
+    /// assert!(required.constraints_compatible(&BooleanField::new().not_null()));
+    /// ```
+    pub fn constraints_compatible(&self, other: &Self) -> bool {
+        BooleanOps::constraints_compatible(&self.data, &other.data)
+    }
+
+    /// Merges two fields into one, keeping the strictest constraints.
+    ///
+    /// The result is NOT NULL if either field is, and takes whichever value and
+    /// default are non-NULL. The display configuration is taken from `self`.
+    ///
+    /// # Returns
+    /// - `Ok` with the merged field
+    /// - `Err(BooleanFieldError::MergeConflict)` if both fields hold different
+    ///   non-NULL values or defaults, or their constraints are not
+    ///   `constraints_compatible`
+    ///
+    /// # Example
+    /// ```
+    /// use dbform::libs::libs_fieldtype::booleanfield_dod::BooleanField;
+    ///
+    /// let merged = BooleanField::<&str>::new().merge(BooleanField::from(true)).unwrap();
+    /// assert_eq!(merged.get_value(), Some(true));
+    ///
+    /// assert!(BooleanField::<&str>::from(true).merge(BooleanField::from(false)).is_err());
+    /// ```
+    pub fn merge(self, other: Self) -> Result<Self, BooleanFieldError> {
+        Ok(Self {
+            data: BooleanOps::merge(&self.data, &other.data)?,
+            ..self
+        })
+    }
+
+    /// Merges two fields, resolving conflicts according to `policy`.
+    ///
+    /// The result is NOT NULL if either field is. With `MergePolicy::Strict`
+    /// this is `merge`; with a preferring policy the preferred side's value and
+    /// default win, and the other side only fills in what NOT NULL requires, so
+    /// the merge always succeeds. The display configuration is taken from `self`.
+    ///
+    /// # Example
+    /// ```
+    /// use dbform::libs::libs_fieldtype::booleanfield_dod::{BooleanField, MergePolicy};
+    ///
+    /// let a = BooleanField::<&str>::from(true);
+    /// let b = BooleanField::<&str>::from(false);
+    /// assert!(a.clone().merge_with(b.clone(), MergePolicy::Strict).is_err());
+    /// let merged = a.merge_with(b, MergePolicy::PreferOther).unwrap();
+    /// assert_eq!(merged.get_value(), Some(false));
+    /// ```
+    pub fn merge_with(self, other: Self, policy: MergePolicy) -> Result<Self, BooleanFieldError> {
+        let data = match policy {
+            MergePolicy::Strict => BooleanOps::merge(&self.data, &other.data)?,
+            MergePolicy::PreferSelf => BooleanOps::merge_prefer(&self.data, &other.data),
+            MergePolicy::PreferOther => BooleanOps::merge_prefer(&other.data, &self.data),
+        };
+        Ok(Self { data, ..self })
+    }
+
+    /// Checks whether `merge_with` would succeed under `policy`, without merging.
+    ///
+    /// Only `MergePolicy::Strict` can fail, when both fields hold different
+    /// non-NULL values or defaults, or their constraints are not
+    /// `constraints_compatible`.
+    ///
+    /// # Example
+    /// ```
+    /// use dbform::libs::libs_fieldtype::booleanfield_dod::{BooleanField, MergePolicy};
+    ///
+    /// let a = BooleanField::<&str>::from(true);
+    /// assert!(a.can_merge(&BooleanField::new(), MergePolicy::Strict));
+    /// assert!(!a.can_merge(&BooleanField::from(false), MergePolicy::Strict));
+    /// assert!(a.can_merge(&BooleanField::from(false), MergePolicy::PreferSelf));
+    /// assert!(a.can_merge(&BooleanField::from(false), MergePolicy::PreferOther));
+    /// ```
+    pub fn can_merge(&self, other: &Self, policy: MergePolicy) -> bool {
+        match policy {
+            MergePolicy::Strict => BooleanOps::merge(&self.data, &other.data).is_ok(),
+            MergePolicy::PreferSelf | MergePolicy::PreferOther => true,
+        }
+    }
+
+    /// Describes the field's state in a human-readable sentence.
+    ///
+    /// Clauses are joined with `"; "` in the order value, NOT NULL, default.
+    /// A NULL value is followed by its reason in parentheses, if one was recorded.
+    /// The NOT NULL clause is omitted for nullable fields and the default
+    /// clause when no default is set.
+    ///
+    /// # Example
+    /// ```
+    /// use dbform::libs::libs_fieldtype::booleanfield_dod::BooleanField;
+    ///
+    /// let mut field = BooleanField::<&str>::new().not_null();
+    /// field.set_value(Some(true)).unwrap();
+    /// assert_eq!(field.explain(), "value is true; NOT NULL; default is false");
+    ///
+    /// assert_eq!(BooleanField::<&str>::new().explain(), "value is NULL");
+    /// ```
+    pub fn explain(&self) -> String {
+        let describe = |value: Option<bool>| match value {
+            Some(true) => "true",
+            Some(false) => "false",
+            None => "NULL",
+        };
+
+        let mut clauses = vec![match self.null_reason() {
+            Some(reason) => format!("value is NULL ({})", reason),
+            None => format!("value is {}", describe(self.data.value())),
+        }];
+        if self.data.not_null() {
+            clauses.push("NOT NULL".to_string());
+        }
+        if let Some(expr) = &self.default_expr {
+            clauses.push(format!("default is ({})", expr));
+        } else if let Some(default) = self.data.default_value() {
+            clauses.push(format!("default is {}", describe(Some(default))));
+        }
+        clauses.join("; ")
+    }
+
+    /// Checks whether the field still holds its default value.
+    ///
+    /// # Returns
+    /// - `Some(true)` if the value equals the literal default
+    /// - `Some(false)` if it differs, including a NULL value
+    /// - `None` if there is no literal default, or a default expression that
+    ///   cannot be evaluated here
+    ///
+    /// # Example
+    /// ```
+    /// use dbform::libs::libs_fieldtype::booleanfield_dod::BooleanField;
+    ///
+    /// let mut field = BooleanField::<&str>::new().default(true);
+    /// assert_eq!(field.holds_default(), Some(true));
+    ///
+    /// field.set_value(Some(false)).unwrap();
+    /// assert_eq!(field.holds_default(), Some(false));
+    ///
+    /// assert_eq!(BooleanField::<&str>::from(true).holds_default(), None);
+    /// ```
+    pub fn holds_default(&self) -> Option<bool> {
+        match self.default_kind() {
+            DefaultKind::Literal(default) => Some(self.data.value() == Some(default)),
+            DefaultKind::None | DefaultKind::Expr(_) => None,
+        }
+    }
+
+    /// Explains the first difference from `other`, or returns `None` if there is none.
+    ///
+    /// Components are compared in the order value, default, NOT NULL, inverted
+    /// storage, display configuration, bidi isolation, and the first mismatch is reported as
+    /// `"<component>: <self> vs <other>"`. Validators are not compared.
+    ///
+    /// # Example
+    /// ```
+    /// use dbform::libs::libs_fieldtype::booleanfield_dod::BooleanField;
+    ///
+    /// let a = BooleanField::<&str>::from(true);
+    /// assert_eq!(a.diff(&a.clone()), None);
+    /// assert_eq!(a.diff(&BooleanField::new()).unwrap(), "value: true vs NULL");
+    /// assert_eq!(
+    ///     a.diff(&BooleanField::from(true).default(false)).unwrap(),
+    ///     "default: none vs false"
+    /// );
+    ///
+    /// let b = BooleanField::<&str>::new().default(true);
+    /// let c = BooleanField::<&str>::new().default(true).not_null();
+    /// assert_eq!(b.diff(&c).unwrap(), "not_null: false vs true");
+    ///
+    /// let d = BooleanField::from(true).with_display("No", "Yes", None);
+    /// assert_eq!(a.diff(&d).unwrap(), "display_config: none vs false=No, true=Yes, null=none");
+    /// ```
+    pub fn diff(&self, other: &Self) -> Option<String>
+    where
+        T: PartialEq,
+    {
+        let value =
+            |value: Option<bool>| value.map_or("NULL".to_string(), |value| value.to_string());
+        let default = |kind: DefaultKind| match kind {
+            DefaultKind::None => "none".to_string(),
+            DefaultKind::Literal(default) => default.to_string(),
+            DefaultKind::Expr(expr) => format!("({})", expr),
+        };
+        let config = |config: &Option<BooleanDisplayConfig<T>>| match config {
+            None => "none".to_string(),
+            Some(config) => format!(
+                "false={}, true={}, null={}",
+                config.false_display,
+                config.true_display,
+                config
+                    .null_display
+                    .as_ref()
+                    .map_or("none".to_string(), |s| s.to_string()),
+            ),
+        };
+
+        if self.data.value() != other.data.value() {
+            return Some(format!(
+                "value: {} vs {}",
+                value(self.data.value()),
+                value(other.data.value())
+            ));
+        }
+        let (a, b) = (self.default_kind(), other.default_kind());
+        if a != b {
+            return Some(format!("default: {} vs {}", default(a), default(b)));
+        }
+        if self.data.not_null() != other.data.not_null() {
+            return Some(format!(
+                "not_null: {} vs {}",
+                self.data.not_null(),
+                other.data.not_null()
+            ));
+        }
+        if self.inverted_storage != other.inverted_storage {
+            return Some(format!(
+                "inverted_storage: {} vs {}",
+                self.inverted_storage, other.inverted_storage
+            ));
+        }
+        if self.display_config != other.display_config {
+            return Some(format!(
+                "display_config: {} vs {}",
+                config(&self.display_config),
+                config(&other.display_config)
+            ));
+        }
+        if self.bidi != other.bidi {
+            return Some(format!("bidi: {} vs {}", self.bidi, other.bidi));
+        }
+        None
+    }
+
+    /// Returns which kind of default the field has.
+    ///
+    /// A default expression takes precedence over the literal default, matching `to_sql`.
+    /// `data().default_value()` still reports only the literal.
+    ///
+    /// # Example
+    /// ```
+    /// use dbform::libs::libs_fieldtype::booleanfield_dod::{BooleanField, DefaultKind};
+    ///
+    /// assert_eq!(BooleanField::<&str>::new().default_kind(), DefaultKind::None);
+    /// assert_eq!(BooleanField::<&str>::new().default(false).default_kind(), DefaultKind::Literal(false));
+    ///
+    /// let field = BooleanField::<&str>::new().with_default_expr("f()".to_string());
+    /// assert_eq!(field.default_kind(), DefaultKind::Expr("f()".to_string()));
+    /// ```
+    pub fn default_kind(&self) -> DefaultKind {
+        match (&self.default_expr, self.data.default_value()) {
+            (Some(expr), _) => DefaultKind::Expr(expr.clone()),
+            (None, Some(default)) => DefaultKind::Literal(default),
+            (None, None) => DefaultKind::None,
+        }
+    }
+
+    /// Returns an `ALTER TABLE ... ADD COLUMN` statement that is safe to re-run.
+    ///
+    /// PostgreSQL gets `ADD COLUMN IF NOT EXISTS`, so applying the statement
+    /// again is a no-op. MySQL and SQLite have no `IF NOT EXISTS` for columns
+    /// (MariaDB does); for them the plain `ADD COLUMN` is emitted
+    /// and the caller must check the schema (e.g. `information_schema.columns`
+    /// or `PRAGMA table_info`) before running it.
+    ///
+    /// # Example
+    /// ```
+    /// use dbform::libs::libs_fieldtype::booleanfield_dod::{BooleanField, SqlDialect};
+    ///
+    /// let field = BooleanField::<&str>::new().not_null().default(true);
+    /// assert_eq!(
+    ///     field.to_sql_idempotent("users", "active", SqlDialect::Postgres),
+    ///     "ALTER TABLE \"users\" ADD COLUMN IF NOT EXISTS \"active\" BOOLEAN NOT NULL DEFAULT TRUE"
+    /// );
+    /// assert_eq!(
+    ///     field.to_sql_idempotent("users", "active", SqlDialect::MySql),
+    ///     "ALTER TABLE `users` ADD COLUMN `active` BOOLEAN NOT NULL DEFAULT 1"
+    /// );
+    /// ```
+    pub fn to_sql_idempotent(&self, table: &str, column: &str, dialect: SqlDialect) -> String {
+        let if_not_exists = match dialect {
+            SqlDialect::Postgres => "IF NOT EXISTS ",
+            SqlDialect::MySql | SqlDialect::Sqlite => "",
+        };
+        format!(
+            "ALTER TABLE {} ADD COLUMN {}{} {}",
+            dialect.quote_ident(table),
+            if_not_exists,
+            dialect.quote_ident(column),
+            self.column_sql(dialect)
+        )
+    }
+
+    /// Returns the statements that add this column to a populated table safely.
+    ///
+    /// For a NOT NULL field this is the standard backfill recipe:
+    /// 1. add the column as nullable, with its default
+    /// 2. `UPDATE` the existing rows that are still NULL to the default
+    /// 3. enforce NOT NULL (`SET NOT NULL` on PostgreSQL, `MODIFY COLUMN` on MySQL)
+    ///
+    /// SQLite cannot add NOT NULL to an existing column, but it fills existing
+    /// rows with the default when a NOT NULL column is added, so it gets a single
+    /// `ADD COLUMN`; its default must then be a constant. A nullable field needs
+    /// no backfill and also gets a single `ADD COLUMN`.
+    ///
+    /// # Example
+    /// ```
+    /// use dbform::libs::libs_fieldtype::booleanfield_dod::{BooleanField, SqlDialect};
+    ///
+    /// let field = BooleanField::<&str>::new().not_null().default(true);
+    /// assert_eq!(
+    ///     field.to_sql_backfill("users", "active", SqlDialect::Postgres),
+    ///     [
+    ///         "ALTER TABLE \"users\" ADD COLUMN \"active\" BOOLEAN DEFAULT TRUE",
+    ///         "UPDATE \"users\" SET \"active\" = TRUE WHERE \"active\" IS NULL",
+    ///         "ALTER TABLE \"users\" ALTER COLUMN \"active\" SET NOT NULL",
+    ///     ]
+    /// );
+    /// assert_eq!(
+    ///     field.to_sql_backfill("users", "active", SqlDialect::MySql)[2],
+    ///     "ALTER TABLE `users` MODIFY COLUMN `active` BOOLEAN NOT NULL DEFAULT 1"
+    /// );
+    /// ```
+    pub fn to_sql_backfill(&self, table: &str, column: &str, dialect: SqlDialect) -> Vec<String> {
+        let table = dialect.quote_ident(table);
+        let column = dialect.quote_ident(column);
+        let add = |definition: String| {
+            format!("ALTER TABLE {} ADD COLUMN {} {}", table, column, definition)
+        };
+
+        if !self.data.not_null() || dialect == SqlDialect::Sqlite {
+            return vec![add(self.column_sql(dialect))];
+        }
+
+        let default_clause = self.default_clause(dialect);
+        let mut nullable = self.data;
+        BooleanOps::set_nullable(&mut nullable);
+        let fill = match (&self.default_expr, self.data.default_value()) {
+            (Some(expr), _) => format!("({})", expr),
+            // A NOT NULL field always has a literal default.
+            (None, default) => dialect
+                .bool_literal(self.stored(default.unwrap_or(false)))
+                .to_string(),
+        };
+        let enforce = match dialect {
+            SqlDialect::Postgres => {
+                format!("ALTER TABLE {} ALTER COLUMN {} SET NOT NULL", table, column)
+            }
+            SqlDialect::MySql | SqlDialect::Sqlite => format!(
+                "ALTER TABLE {} MODIFY COLUMN {} {}",
+                table,
+                column,
+                self.column_sql(dialect)
+            ),
+        };
+        vec![
+            add(BooleanOps::to_sql_with_default(
+                &nullable,
+                default_clause.as_deref(),
+            )),
+            format!(
+                "UPDATE {} SET {} = {} WHERE {} IS NULL",
+                table, column, fill, column
+            ),
+            enforce,
+        ]
+    }
+
+    /// Lists how each of the 13 valid state codes renders in `dialect`, e.g. for reference docs.
+    ///
+    /// # Returns
+    /// One `(state_code, column type DDL, value literal)` row per valid state, in
+    /// ascending state code order. A NULL value renders as `NULL`.
+    ///
+    /// # Example
+    /// ```
+    /// use dbform::libs::libs_fieldtype::booleanfield_dod::{BooleanField, SqlDialect};
+    ///
+    /// let matrix = BooleanField::<&str>::sql_matrix(SqlDialect::Postgres);
+    /// assert_eq!(matrix.len(), 13);
+    /// assert_eq!(matrix[0], (0, "BOOLEAN DEFAULT FALSE".to_string(), "FALSE".to_string()));
+    /// assert_eq!(matrix[8], (10, "BOOLEAN".to_string(), "NULL".to_string()));
+    /// assert_eq!(
+    ///     matrix[12],
+    ///     (21, "BOOLEAN NOT NULL DEFAULT TRUE".to_string(), "TRUE".to_string())
+    /// );
+    /// ```
+    pub fn sql_matrix(dialect: SqlDialect) -> Vec<(u8, String, String)> {
+        (0..=u8::MAX)
+            .filter_map(|byte| PackedBooleanData::from_byte(byte).ok())
+            .map(|data| {
+                let default_clause = BooleanOps::default_clause(&data, dialect);
+                let ddl = BooleanOps::to_sql_with_default(&data, default_clause.as_deref());
+                let literal = data
+                    .value()
+                    .map_or("NULL", |value| dialect.bool_literal(value));
+                (data.to_byte(), ddl, literal.to_string())
+            })
+            .collect()
+    }
+
+    /// Returns a WHERE predicate matching rows whose `column` equals the current value.
+    ///
+    /// NULL produces `IS NULL`, since `= NULL` never matches in SQL.
+    ///
+    /// # Example
+    /// ```
+    /// use dbform::libs::libs_fieldtype::booleanfield_dod::{BooleanField, SqlDialect};
+    ///
+    /// let field = BooleanField::<&str>::from(true);
+    /// assert_eq!(field.to_sql_predicate("active", SqlDialect::Postgres), "\"active\" = TRUE");
+    /// assert_eq!(field.to_sql_predicate("active", SqlDialect::MySql), "`active` = 1");
+    ///
+    /// let field = BooleanField::<&str>::new();
+    /// assert_eq!(field.to_sql_predicate("active", SqlDialect::Sqlite), "\"active\" IS NULL");
+    /// ```
+    pub fn to_sql_predicate(&self, column: &str, dialect: SqlDialect) -> String {
+        let column = dialect.quote_ident(column);
+        match self.data.value() {
+            Some(value) => format!("{} = {}", column, dialect.bool_literal(self.stored(value))),
+            None => format!("{} IS NULL", column),
+        }
+    }
+
+    /// Returns the column type definition with the DEFAULT clause spelled for `dialect`
+    fn column_sql(&self, dialect: SqlDialect) -> String {
+        let default_clause = self.default_clause(dialect);
+        BooleanOps::to_sql_with_default(&self.data, default_clause.as_deref())
+    }
+
+    /// Returns Rust source that rebuilds this field through the builder API.
+    ///
+    /// The display configuration is not included. The type parameter is written
+    /// with `std::any::type_name`. When the value differs from what the builder
+    /// chain produces, the literal becomes a block that calls `set_value`.
+    ///
+    /// # Example
+    /// ```
+    /// use dbform::libs::libs_fieldtype::booleanfield_dod::BooleanField;
+    ///
+    /// let field = BooleanField::<&str>::new().default(true).not_null();
+    /// assert_eq!(field.to_rust_literal(), "BooleanField::<&str>::new().default(true).not_null()");
+    ///
+    /// let field = BooleanField::<&str>::from(false);
+    /// assert_eq!(
+    ///     field.to_rust_literal(),
+    ///     "{ let mut field = BooleanField::<&str>::new(); field.set_value(Some(false)).unwrap(); field }"
+    /// );
+    /// ```
+    pub fn to_rust_literal(&self) -> String {
+        let mut code = format!("BooleanField::<{}>::new()", std::any::type_name::<T>());
+        // Replay the builders in an order where each one keeps the previous effect.
+        let mut replay = BooleanOps::new_data();
+        if let Some(default) = self.data.default_value() {
+            code.push_str(&format!(".default({})", default));
+            BooleanOps::set_default(&mut replay, default);
+        }
+        if self.data.not_null() {
+            code.push_str(".not_null()");
+            BooleanOps::set_not_null(&mut replay);
+        }
+        if let Some(expr) = &self.default_expr {
+            code.push_str(&format!(".with_default_expr({:?}.to_string())", expr));
+        }
+        if self.inverted_storage {
+            code.push_str(".with_inverted_storage()");
+        }
+
+        if replay.value() == self.data.value() {
+            code
+        } else {
+            format!(
+                "{{ let mut field = {}; field.set_value({:?}).unwrap(); field }}",
+                code,
+                self.data.value()
+            )
+        }
+    }
+
+    /// Returns the SQL type definition as a string with all constraints
+    pub fn to_sql(&self) -> String {
+        self.column_sql(SqlDialect::Postgres)
+    }
+
+    /// Builds a sea-query column definition named `name` with the field's type,
+    /// nullability and default.
+    ///
+    /// A default expression is passed through as custom SQL and takes precedence
+    /// over the literal default, matching `to_sql`.
+    ///
+    /// # Example
+    /// ```
+    /// use dbform::libs::libs_fieldtype::booleanfield_dod::BooleanField;
+    /// use sea_query::{Alias, PostgresQueryBuilder, Table};
+    ///
+    /// let field = BooleanField::<&str>::new().not_null().default(true);
+    /// let sql = Table::create()
+    ///     .table(Alias::new("users"))
+    ///     .col(&mut field.to_sea_query_column("active"))
+    ///     .to_string(PostgresQueryBuilder);
+    /// assert_eq!(sql, r#"CREATE TABLE "users" ( "active" bool NOT NULL DEFAULT TRUE )"#);
+    /// ```
+    #[cfg(feature = "sea-query")]
+    pub fn to_sea_query_column(&self, name: &str) -> sea_query::ColumnDef {
+        let mut column = sea_query::ColumnDef::new(sea_query::Alias::new(name));
+        column.boolean();
+        if self.data.not_null() {
+            column.not_null();
+        } else {
+            column.null();
+        }
+        match (&self.default_expr, self.data.default_value()) {
+            (Some(expr), _) => {
+                column.default(sea_query::Expr::cust(expr.as_str()));
+            }
+            (None, Some(default)) => {
+                column.default(self.stored(default));
+            }
+            (None, None) => {}
+        }
+        column
+    }
+
+    /// Renders a GraphQL SDL field definition named `name`.
+    ///
+    /// NOT NULL fields are typed `Boolean!` and nullable ones `Boolean`. A
+    /// literal default is noted in a trailing comment, since default values are
+    /// only valid SDL on input fields; a SQL default expression is left out.
+    ///
+    /// # Example
+    /// ```
+    /// use dbform::libs::libs_fieldtype::booleanfield_dod::BooleanField;
+    ///
+    /// let field = BooleanField::<&str>::new().not_null().default(true);
+    /// assert_eq!(field.to_graphql_field("active"), "active: Boolean! # default: true");
+    ///
+    /// let field = BooleanField::<&str>::new();
+    /// assert_eq!(field.to_graphql_field("verified"), "verified: Boolean");
+    /// ```
+    #[cfg(feature = "graphql")]
+    pub fn to_graphql_field(&self, name: &str) -> String {
+        let suffix = if self.data.not_null() { "!" } else { "" };
+        match self.default_kind() {
+            DefaultKind::Literal(default) => {
+                format!("{}: Boolean{} # default: {}", name, suffix, default)
+            }
+            DefaultKind::None | DefaultKind::Expr(_) => format!("{}: Boolean{}", name, suffix),
+        }
+    }
+
+    /// Returns only the DEFAULT clause of the column definition.
+    ///
+    /// # Returns
+    /// - `Some("DEFAULT (expr)")` if a default expression is set
+    /// - `Some("DEFAULT TRUE")` style clause spelled for `dialect` for a literal default
+    /// - `None` if the field has no default
+    ///
+    /// # Example
+    /// ```
+    /// use dbform::libs::libs_fieldtype::booleanfield_dod::{BooleanField, SqlDialect};
+    ///
+    /// let field = BooleanField::<&str>::new().default(true);
+    /// assert_eq!(field.default_clause(SqlDialect::Postgres).as_deref(), Some("DEFAULT TRUE"));
+    /// assert_eq!(field.default_clause(SqlDialect::MySql).as_deref(), Some("DEFAULT 1"));
+    ///
+    /// assert_eq!(BooleanField::<&str>::new().default_clause(SqlDialect::Sqlite), None);
+    /// ```
+    pub fn default_clause(&self, dialect: SqlDialect) -> Option<String> {
+        match &self.default_expr {
+            Some(expr) => Some(format!("DEFAULT ({})", expr)),
+            None => self
+                .data
+                .default_value()
+                .map(|default| format!("DEFAULT {}", dialect.bool_literal(self.stored(default)))),
+        }
+    }
+
+    /// Builds a nullable field by applying a three-state predicate to `item`.
+    ///
+    /// # Example
+    /// ```
+    /// use dbform::libs::libs_fieldtype::booleanfield_dod::BooleanField;
+    ///
+    /// struct User { age: Option<u32> }
+    ///
+    /// let is_adult = |user: &User| user.age.map(|age| age >= 18);
+    /// let field = BooleanField::<&str>::from_predicate(&User { age: Some(30) }, is_adult);
+    /// assert_eq!(field.get_value(), Some(true));
+    ///
+    /// let field = BooleanField::<&str>::from_predicate(&User { age: None }, is_adult);
+    /// assert_eq!(field.get_value(), None);
+    /// ```
+    pub fn from_predicate<U, F: Fn(&U) -> Option<bool>>(item: &U, f: F) -> Self {
+        Self::from(f(item))
+    }
+
+    /// Builds a nullable field from the environment variable `key`.
+    ///
+    /// The variable is parsed with the same tokens as `FromStr`
+    /// (case-insensitive, surrounding whitespace ignored):
+    /// - truthy: `true`, `t`, `yes`, `y`, `on`, `1`
+    /// - falsy: `false`, `f`, `no`, `n`, `off`, `0`
+    /// - NULL: `null` or an empty value
+    ///
+    /// # Returns
+    /// - `Ok` with a NULL field if the variable is not set
+    /// - `Err(BooleanFieldError::InvalidToken)` if the variable holds any other
+    ///   token or is not valid Unicode
+    ///
+    /// # Example
+    /// ```
+    /// use dbform::libs::libs_fieldtype::booleanfield_dod::BooleanField;
+    ///
+    /// let field = BooleanField::<&str>::from_env("BOOLEANFIELD_DOC_UNSET_FLAG").unwrap();
+    /// assert_eq!(field.get_value(), None);
+    /// ```
+    pub fn from_env(key: &str) -> Result<Self, BooleanFieldError> {
+        match env::var(key) {
+            Ok(raw) => raw.parse(),
+            Err(env::VarError::NotPresent) => Ok(Self::new()),
+            Err(env::VarError::NotUnicode(raw)) => Err(BooleanFieldError::InvalidToken(
+                raw.to_string_lossy().into_owned(),
+            )),
+        }
+    }
+
+    /// Builds a field from a generic string map with the keys `not_null`,
+    /// `default` and `value`.
+    ///
+    /// Each value is parsed with the `FromStr` tokens. The keys are applied in
+    /// a fixed order, whatever the map's iteration order: `default`, then
+    /// `not_null`, then `value`. A missing key keeps the `new()` behavior and a
+    /// NULL `default` means no default. Other keys are ignored, so the map can
+    /// carry unrelated settings.
+    ///
+    /// # Returns
+    /// - `Err(BooleanFieldError::InvalidToken)` if a value is not a valid token,
+    ///   or `not_null` is NULL
+    /// - `Err(BooleanFieldError::NullNotAllowed)` if `not_null` is true and
+    ///   `default` or `value` is explicitly NULL
+    ///
+    /// # Example
+    /// ```
+    /// use std::collections::HashMap;
+    /// use dbform::libs::libs_fieldtype::booleanfield_dod::{BooleanField, BooleanFieldError};
+    ///
+    /// let map = HashMap::from([
+    ///     ("not_null".to_string(), "yes".to_string()),
+    ///     ("default".to_string(), "true".to_string()),
+    ///     ("value".to_string(), "0".to_string()),
+    /// ]);
+    /// let field = BooleanField::<&str>::from_config_map(&map).unwrap();
+    /// assert!(field.is_not_null());
+    /// assert_eq!(field.data().default_value(), Some(true));
+    /// assert_eq!(field.get_value(), Some(false));
+    ///
+    /// let map = HashMap::from([("default".to_string(), "off".to_string())]);
+    /// let field = BooleanField::<&str>::from_config_map(&map).unwrap();
+    /// assert!(!field.is_not_null());
+    /// assert_eq!(field.get_value(), Some(false));
+    ///
+    /// let map = HashMap::from([
+    ///     ("not_null".to_string(), "true".to_string()),
+    ///     ("value".to_string(), "null".to_string()),
+    /// ]);
+    /// assert_eq!(
+    ///     BooleanField::<&str>::from_config_map(&map).unwrap_err(),
+    ///     BooleanFieldError::NullNotAllowed
+    /// );
+    /// ```
+    pub fn from_config_map(map: &HashMap<String, String>) -> Result<Self, BooleanFieldError> {
+        let token = |key: &str| map.get(key).map(|raw| parse_token(raw)).transpose();
+        let default = token("default")?;
+        let not_null = match token("not_null")? {
+            Some(Some(not_null)) => not_null,
+            Some(None) => return Err(BooleanFieldError::InvalidToken(map["not_null"].clone())),
+            None => false,
+        };
+        let value = token("value")?;
+
+        if not_null && (default == Some(None) || value == Some(None)) {
+            return Err(BooleanFieldError::NullNotAllowed);
+        }
+
+        let mut field = Self::new();
+        if let Some(Some(default)) = default {
+            field = field.default(default);
+        }
+        if not_null {
+            field = field.not_null();
+        }
+        if let Some(value) = value {
+            field
+                .set_value(value)
+                .map_err(|_| BooleanFieldError::NullNotAllowed)?;
+        }
+        Ok(field)
+    }
+
+    /// Parses a nullable field, treating `null_token` as NULL.
+    ///
+    /// The comparison with `null_token` is exact and case-sensitive, which suits
+    /// sentinels such as `NA`, `-` or PostgreSQL COPY's `\N`. Any other input is
+    /// parsed with the `FromStr` tokens.
+    ///
+    /// # Example
+    /// ```
+    /// use dbform::libs::libs_fieldtype::booleanfield_dod::BooleanField;
+    ///
+    /// let field = BooleanField::<&str>::from_str_with_null("\\N", "\\N").unwrap();
+    /// assert_eq!(field.get_value(), None);
+    ///
+    /// let field = BooleanField::<&str>::from_str_with_null("t", "\\N").unwrap();
+    /// assert_eq!(field.get_value(), Some(true));
+    ///
+    /// assert!(BooleanField::<&str>::from_str_with_null("na", "NA").is_err());
+    /// ```
+    pub fn from_str_with_null(s: &str, null_token: &str) -> Result<Self, BooleanFieldError> {
+        if s == null_token {
+            Ok(Self::new())
+        } else {
+            s.parse()
+        }
+    }
+
+    /// Parses a nullable field like `FromStr`, but turns unrecognized tokens into NULL.
+    ///
+    /// This never fails, which also means it can hide data problems: a typo such
+    /// as `"ture"` silently becomes NULL. Prefer `parse` unless the input is known
+    /// to be dirty and NULL is an acceptable outcome.
+    ///
+    /// # Example
+    /// ```
+    /// use dbform::libs::libs_fieldtype::booleanfield_dod::BooleanField;
+    ///
+    /// assert_eq!(BooleanField::<&str>::from_str_lenient("true").get_value(), Some(true));
+    /// assert_eq!(BooleanField::<&str>::from_str_lenient("false").get_value(), Some(false));
+    /// for garbage in ["maybe", "42", ""] {
+    ///     assert_eq!(BooleanField::<&str>::from_str_lenient(garbage).get_value(), None);
+    /// }
+    /// ```
+    pub fn from_str_lenient(s: &str) -> Self {
+        Self::from(parse_token(s).unwrap_or(None))
+    }
+
+    /// Parses a delimited line such as `"t,f,,t,1,0"` into one nullable field per token.
+    ///
+    /// Tokens are parsed with the `FromStr` tokens, so an empty token is NULL.
+    /// A single trailing delimiter is ignored, and an empty line yields no fields.
+    /// Each token is parsed independently, so an invalid token does not stop the rest.
+    ///
+    /// # Example
+    /// ```
+    /// use dbform::libs::libs_fieldtype::booleanfield_dod::BooleanField;
+    ///
+    /// let values: Vec<_> = BooleanField::<&str>::parse_line("t,f,,1,", ',')
+    ///     .into_iter()
+    ///     .map(|field| field.unwrap().get_value())
+    ///     .collect();
+    /// assert_eq!(values, [Some(true), Some(false), None, Some(true)]);
+    ///
+    /// let fields = BooleanField::<&str>::parse_line("yes;maybe;no", ';');
+    /// assert!(fields[0].is_ok());
+    /// assert!(fields[1].is_err());
+    /// assert!(fields[2].is_ok());
+    /// ```
+    pub fn parse_line(line: &str, delimiter: char) -> Vec<Result<Self, BooleanFieldError>> {
+        if line.is_empty() {
+            return Vec::new();
+        }
+        let line = line.strip_suffix(delimiter).unwrap_or(line);
+        line.split(delimiter).map(str::parse).collect()
+    }
+
+    /// Parses one nullable field per line from an async reader.
+    ///
+    /// Each line is parsed with the `FromStr` tokens, so an empty line is NULL.
+    /// Read failures are yielded as `BooleanFieldError::Io` items.
+    ///
+    /// # Example
+    /// ```
+    /// use dbform::libs::libs_fieldtype::booleanfield_dod::BooleanField;
+    /// use tokio_stream::StreamExt;
+    ///
+    /// # #[tokio::main(flavor = "current_thread")]
+    /// # async fn main() {
+    /// let input: &[u8] = b"true\nno\n\nmaybe\n";
+    /// let values: Vec<_> = BooleanField::<&str>::parse_stream_async(input)
+    ///     .map(|field| field.map(|field| field.get_value()))
+    ///     .collect()
+    ///     .await;
+    ///
+    /// assert_eq!(values[..3], [Ok(Some(true)), Ok(Some(false)), Ok(None)]);
+    /// assert!(values[3].is_err());
+    /// # }
+    /// ```
+    #[cfg(feature = "tokio")]
+    pub fn parse_stream_async<R: tokio::io::AsyncBufRead>(
+        reader: R,
+    ) -> impl tokio_stream::Stream<Item = Result<Self, BooleanFieldError>> {
+        use tokio::io::AsyncBufReadExt;
+        use tokio_stream::StreamExt;
+
+        tokio_stream::wrappers::LinesStream::new(reader.lines()).map(|line| {
+            line.map_err(BooleanFieldError::from)
+                .and_then(|line| line.parse())
+        })
+    }
+
+    /// Parses a YAML 1.1 boolean scalar into a nullable field.
+    ///
+    /// Matching is case-insensitive and ignores surrounding whitespace:
+    /// - truthy: `y`, `yes`, `true`, `on`
+    /// - falsy: `n`, `no`, `false`, `off`
+    /// - NULL: `~`, `null` or an empty scalar
+    ///
+    /// Unlike `FromStr`, numeric spellings such as `1`/`0` are rejected, as YAML
+    /// reads them as integers.
+    ///
+    /// # Example
+    /// ```
+    /// use dbform::libs::libs_fieldtype::booleanfield_dod::BooleanField;
+    ///
+    /// assert_eq!(BooleanField::<&str>::from_yaml_bool("On").unwrap().get_value(), Some(true));
+    /// assert_eq!(BooleanField::<&str>::from_yaml_bool("N").unwrap().get_value(), Some(false));
+    /// assert_eq!(BooleanField::<&str>::from_yaml_bool("~").unwrap().get_value(), None);
+    /// assert!(BooleanField::<&str>::from_yaml_bool("1").is_err());
+    /// ```
+    pub fn from_yaml_bool(s: &str) -> Result<Self, BooleanFieldError> {
+        let value = match s.trim().to_ascii_lowercase().as_str() {
+            "y" | "yes" | "true" | "on" => Some(true),
+            "n" | "no" | "false" | "off" => Some(false),
+            "~" | "null" | "" => None,
+            _ => return Err(BooleanFieldError::InvalidToken(s.to_string())),
+        };
+        Ok(Self::from(value))
+    }
+
+    /// Parses a boolean cell from a pandas CSV export into a nullable field.
+    ///
+    /// Matching is exact, following pandas' own spellings:
+    /// - truthy: `True`, `1`, `1.0`
+    /// - falsy: `False`, `0`, `0.0`
+    /// - NULL: `NaN`, `nan`, `<NA>`, `None` or an empty cell
+    ///
+    /// `1.0`/`0.0` appear when a boolean column with missing values was upcast
+    /// to float before export.
+    ///
+    /// # Example
+    /// ```
+    /// use dbform::libs::libs_fieldtype::booleanfield_dod::BooleanField;
+    ///
+    /// assert_eq!(BooleanField::<&str>::from_pandas_token("True").unwrap().get_value(), Some(true));
+    /// assert_eq!(BooleanField::<&str>::from_pandas_token("0").unwrap().get_value(), Some(false));
+    /// for null in ["NaN", "<NA>", "None", ""] {
+    ///     assert_eq!(BooleanField::<&str>::from_pandas_token(null).unwrap().get_value(), None);
+    /// }
+    /// assert!(BooleanField::<&str>::from_pandas_token("true").is_err());
+    /// ```
+    pub fn from_pandas_token(s: &str) -> Result<Self, BooleanFieldError> {
+        let value = match s {
+            "True" | "1" | "1.0" => Some(true),
+            "False" | "0" | "0.0" => Some(false),
+            "NaN" | "nan" | "<NA>" | "None" | "" => None,
+            _ => return Err(BooleanFieldError::InvalidToken(s.to_string())),
+        };
+        Ok(Self::from(value))
+    }
+
+    /// Encodes the value as a single CBOR simple value: `false`, `true` or `null`.
+    ///
+    /// Only the value is encoded, which matches DAG-CBOR's bool and null kinds.
+    ///
+    /// # Example
+    /// ```
+    /// use dbform::libs::libs_fieldtype::booleanfield_dod::BooleanField;
+    ///
+    /// assert_eq!(BooleanField::<&str>::from(true).to_cbor(), [0xf5]);
+    /// assert_eq!(BooleanField::<&str>::new().to_cbor(), [0xf6]);
+    ///
+    /// let field = BooleanField::<&str>::from_cbor(&[0xf4]).unwrap();
+    /// assert_eq!(field.get_value(), Some(false));
+    /// assert!(BooleanField::<&str>::from_cbor(&[0x01]).is_err());
+    /// ```
+    #[cfg(feature = "cbor")]
+    pub fn to_cbor(&self) -> Vec<u8> {
+        let byte = match self.data.value() {
+            Some(false) => Self::CBOR_FALSE,
+            Some(true) => Self::CBOR_TRUE,
+            None => Self::CBOR_NULL,
+        };
+        vec![byte]
+    }
+
+    /// Decodes a nullable field from a CBOR `false`, `true` or `null`.
+    ///
+    /// # Returns
+    /// `Err(BooleanFieldError::InvalidEncoding)` for any other input, including trailing bytes.
+    #[cfg(feature = "cbor")]
+    pub fn from_cbor(bytes: &[u8]) -> Result<Self, BooleanFieldError> {
+        let value = match bytes {
+            [Self::CBOR_FALSE] => Some(false),
+            [Self::CBOR_TRUE] => Some(true),
+            [Self::CBOR_NULL] => None,
+            _ => return Err(BooleanFieldError::InvalidEncoding("CBOR bool or null")),
+        };
+        Ok(Self::from(value))
+    }
+
+    /// Returns the stored value as `Some(1)`, `Some(0)` or `None` for NULL.
+    ///
+    /// This is the integer column mapping, not the packed state code. With
+    /// `with_inverted_storage`, true is stored as 0. `from_int` builds a plain
+    /// field; use `load_int` to read into an inverted one.
+    ///
+    /// # Example
+    /// ```
+    /// use dbform::libs::libs_fieldtype::booleanfield_dod::{BooleanField, BooleanFieldError};
+    ///
+    /// assert_eq!(BooleanField::<&str>::from(true).to_int(), Some(1));
+    /// assert_eq!(BooleanField::<&str>::from_int(Some(0)).unwrap().get_value(), Some(false));
+    /// assert_eq!(BooleanField::<&str>::from_int(None).unwrap().get_value(), None);
+    /// assert_eq!(
+    ///     BooleanField::<&str>::from_int(Some(2)).unwrap_err(),
+    ///     BooleanFieldError::OutOfRange(2)
+    /// );
+    /// ```
+    pub fn to_int(&self) -> Option<u8> {
+        self.data.value().map(|value| u8::from(self.stored(value)))
+    }
+
+    /// Builds a nullable field from `Some(1)`, `Some(0)` or `None`.
+    ///
+    /// The result is a plain field, so `1` is always true. Only `load_int`
+    /// reads through `with_inverted_storage`.
+    ///
+    /// # Returns
+    /// `Err(BooleanFieldError::OutOfRange)` for any integer other than 0 or 1.
+    pub fn from_int(value: Option<u8>) -> Result<Self, BooleanFieldError> {
+        let value = match value {
+            Some(0) => Some(false),
+            Some(1) => Some(true),
+            Some(n) => return Err(BooleanFieldError::OutOfRange(n.into())),
+            None => None,
+        };
+        Ok(Self::from(value))
+    }
+
+    /// Sets the value from a stored `Some(1)`, `Some(0)` or `None`, honoring
+    /// `with_inverted_storage` and the field's constraints.
+    ///
+    /// # Returns
+    /// - `Err(BooleanFieldError::OutOfRange)` for any integer other than 0 or 1
+    /// - `Err(BooleanFieldError::NullNotAllowed)` for `None` on a NOT NULL field
+    /// - `Err(BooleanFieldError::Validation)` if a validator rejects the value
+    ///
+    /// # Example
+    /// ```
+    /// use dbform::libs::libs_fieldtype::booleanfield_dod::BooleanField;
+    ///
+    /// let mut enabled = BooleanField::<&str>::new().with_inverted_storage();
+    /// enabled.load_int(Some(0)).unwrap();
+    /// assert_eq!(enabled.get_value(), Some(true));
+    /// ```
+    pub fn load_int(&mut self, value: Option<u8>) -> Result<(), BooleanFieldError> {
+        let value = match value {
+            Some(0) => Some(self.stored(false)),
+            Some(1) => Some(self.stored(true)),
+            Some(n) => return Err(BooleanFieldError::OutOfRange(n.into())),
+            None => None,
+        };
+        let mut data = self.data;
+        BooleanOps::set_value(&mut data, value).map_err(|_| BooleanFieldError::NullNotAllowed)?;
+        for validator in &self.validators {
+            validator
+                .validate(value)
+                .map_err(BooleanFieldError::Validation)?;
+        }
+        self.data = data;
+        self.null_reason = None;
+        Ok(())
+    }
+
+    /// Returns the value as a balanced trit: `1` for true, `-1` for false, `0` for NULL.
+    ///
+    /// Unlike `to_int`, this is the logical value; `with_inverted_storage` does
+    /// not affect it.
+    ///
+    /// # Example
+    /// ```
+    /// use dbform::libs::libs_fieldtype::booleanfield_dod::{BooleanField, BooleanFieldError};
+    ///
+    /// assert_eq!(BooleanField::<&str>::from(false).to_trit(), -1);
+    /// assert_eq!(BooleanField::<&str>::new().to_trit(), 0);
+    /// assert_eq!(BooleanField::<&str>::from_trit(1).unwrap().get_value(), Some(true));
+    /// assert_eq!(BooleanField::<&str>::from_trit(0).unwrap().get_value(), None);
+    /// assert_eq!(
+    ///     BooleanField::<&str>::from_trit(2).unwrap_err(),
+    ///     BooleanFieldError::OutOfRange(2)
+    /// );
+    /// ```
+    pub fn to_trit(&self) -> i8 {
+        match self.data.value() {
+            Some(true) => 1,
+            Some(false) => -1,
+            None => 0,
+        }
+    }
+
+    /// Builds a nullable field from a balanced trit `1`, `-1` or `0`.
+    ///
+    /// # Returns
+    /// `Err(BooleanFieldError::OutOfRange)` for any other integer.
+    pub fn from_trit(trit: i8) -> Result<Self, BooleanFieldError> {
+        let value = match trit {
+            1 => Some(true),
+            -1 => Some(false),
+            0 => None,
+            n => return Err(BooleanFieldError::OutOfRange(n.into())),
+        };
+        Ok(Self::from(value))
+    }
+
+    /// Returns the stored value as a SQL literal for `dialect`, or `NULL`.
+    ///
+    /// # Example
+    /// ```
+    /// use dbform::libs::libs_fieldtype::booleanfield_dod::{BooleanField, SqlDialect};
+    ///
+    /// assert_eq!(BooleanField::<&str>::from(true).to_sql_value(SqlDialect::Postgres), "TRUE");
+    /// assert_eq!(BooleanField::<&str>::new().to_sql_value(SqlDialect::MySql), "NULL");
+    /// ```
+    pub fn to_sql_value(&self, dialect: SqlDialect) -> String {
+        match self.data.value() {
+            Some(value) => dialect.bool_literal(self.stored(value)).to_string(),
+            None => "NULL".to_string(),
+        }
+    }
+
+    /// Reads bit `bit` of a flag register; a register bit is never NULL.
+    ///
+    /// # Panics
+    /// Panics if `bit` is 64 or more.
+    ///
+    /// # Example
+    /// ```
+    /// use dbform::libs::libs_fieldtype::booleanfield_dod::BooleanField;
+    ///
+    /// let register = 0b1010;
+    /// assert_eq!(BooleanField::<&str>::from_bit(register, 1).get_value(), Some(true));
+    /// assert_eq!(BooleanField::<&str>::from_bit(register, 2).get_value(), Some(false));
+    ///
+    /// let mut register = 0;
+    /// BooleanField::<&str>::from(true).set_into_register(&mut register, 3).unwrap();
+    /// assert_eq!(register, 0b1000);
+    /// assert!(BooleanField::<&str>::new().set_into_register(&mut register, 3).is_err());
+    /// ```
+    pub fn from_bit(register: u64, bit: u32) -> Self {
+        assert!(
+            bit < u64::BITS,
+            "bit {bit} out of range for a 64-bit register"
+        );
+        Self::from(register & (1 << bit) != 0)
+    }
+
+    /// Writes the value into bit `bit` of a flag register.
+    ///
+    /// # Returns
+    /// `Err(BooleanFieldError::NullNotAllowed)` for a NULL value, which a
+    /// register bit cannot hold; the register is left unchanged.
+    ///
+    /// # Panics
+    /// Panics if `bit` is 64 or more.
+    pub fn set_into_register(&self, register: &mut u64, bit: u32) -> Result<(), BooleanFieldError> {
+        assert!(
+            bit < u64::BITS,
+            "bit {bit} out of range for a 64-bit register"
+        );
+        match self.data.value() {
+            Some(true) => *register |= 1 << bit,
+            Some(false) => *register &= !(1 << bit),
+            None => return Err(BooleanFieldError::NullNotAllowed),
+        }
+        Ok(())
+    }
+
+    /// Reads entry `index` from an Arrow-style value bitmap and validity bitmap.
+    ///
+    /// Both bitmaps are LSB-first: entry `i` is bit `i % 8` of byte `i / 8`.
+    /// A cleared validity bit gives NULL; otherwise the value bit is the value.
+    ///
+    /// # Panics
+    /// Panics if either bitmap is too short to contain bit `index`.
+    ///
+    /// # Example
+    /// ```
+    /// use dbform::libs::libs_fieldtype::booleanfield_dod::BooleanField;
+    ///
+    /// let values = [0b0000_0101];
+    /// let validity = [0b0000_0011];
+    /// assert_eq!(BooleanField::<&str>::from_buffers(&values, &validity, 0).get_value(), Some(true));
+    /// assert_eq!(BooleanField::<&str>::from_buffers(&values, &validity, 1).get_value(), Some(false));
+    /// assert_eq!(BooleanField::<&str>::from_buffers(&values, &validity, 2).get_value(), None);
+    /// ```
+    pub fn from_buffers(values: &[u8], validity: &[u8], index: usize) -> Self {
+        let bit = |bitmap: &[u8]| bitmap[index / 8] & (1 << (index % 8)) != 0;
+        let is_valid = bit(validity);
+        let value = bit(values);
+        Self::from(is_valid.then_some(value))
+    }
+
+    /// Writes the packed state byte to `w`.
+    ///
+    /// The record is exactly one byte wide; the display configuration is not written.
+    pub fn write_le<W: Write>(&self, w: &mut W) -> io::Result<()> {
+        w.write_all(&[self.data.to_byte()])
+    }
+
+    /// Reads a field written by `write_le`, validating the state byte.
+    ///
+    /// # Returns
+    /// - `Ok` with a field without display configuration
+    /// - `Err(BooleanFieldError::InvalidState)` if the byte is not a valid state
+    /// - `Err(BooleanFieldError::Io)` if the byte could not be read
+    ///
+    /// # Example
+    /// ```
+    /// use std::io::Cursor;
+    /// use dbform::libs::libs_fieldtype::booleanfield_dod::{BooleanField, BooleanFieldError};
+    ///
+    /// let field = BooleanField::<&str>::new().not_null().default(true);
+    /// let mut cursor = Cursor::new(Vec::new());
+    /// field.write_le(&mut cursor).unwrap();
+    ///
+    /// cursor.set_position(0);
+    /// let read = BooleanField::<&str>::read_le(&mut cursor).unwrap();
+    /// assert_eq!(read.to_sql(), field.to_sql());
+    ///
+    /// let mut corrupt = Cursor::new(vec![3u8]);
+    /// assert_eq!(
+    ///     BooleanField::<&str>::read_le(&mut corrupt).unwrap_err(),
+    ///     BooleanFieldError::InvalidState(3)
+    /// );
+    /// ```
+    pub fn read_le<R: Read>(r: &mut R) -> Result<Self, BooleanFieldError> {
+        let mut byte = [0u8; 1];
+        r.read_exact(&mut byte)?;
+        let mut field = Self::new();
+        field.data = PackedBooleanData::from_byte(byte[0])?;
+        Ok(field)
+    }
+
+    /// Aggregates child checkboxes into the state of a "select all" parent.
+    ///
+    /// # Returns
+    /// A new nullable `BooleanField` that is:
+    /// - `true` if every child is `true`
+    /// - `false` if every child is `false`
+    /// - NULL (indeterminate) if the children are mixed or the slice is empty
+    ///
+    /// A NULL child counts as neither checked nor unchecked, so any NULL child
+    /// makes the parent indeterminate.
+    ///
+    /// # Example
+    /// ```
+    /// use dbform::libs::libs_fieldtype::booleanfield_dod::BooleanField;
+    ///
+    /// let checked = [BooleanField::<&str>::from(true), BooleanField::from(true)];
+    /// assert_eq!(BooleanField::aggregate_tri_state(&checked).get_value(), Some(true));
+    ///
+    /// let mixed = [BooleanField::<&str>::from(true), BooleanField::from(false)];
+    /// assert_eq!(BooleanField::aggregate_tri_state(&mixed).get_value(), None);
+    ///
+    /// assert_eq!(BooleanField::<&str>::aggregate_tri_state(&[]).get_value(), None);
+    /// ```
+    pub fn aggregate_tri_state(children: &[Self]) -> Self {
+        let mut values = children.iter().map(|child| child.get_value());
+        let first = values.next().flatten();
+        let value = match first {
+            Some(_) if values.all(|value| value == first) => first,
+            _ => None,
+        };
+        Self::from(value)
+    }
+
+    /// Checks whether at least `k` of `fields` are true, under three-state logic.
+    ///
+    /// # Returns
+    /// A new nullable `BooleanField` that is:
+    /// - `true` if at least `k` fields are `true` (always, for `k == 0`)
+    /// - `false` if fewer than `k` fields could be `true` even if every NULL were
+    /// - NULL otherwise, since the NULL fields decide the outcome
+    ///
+    /// # Example
+    /// ```
+    /// use dbform::libs::libs_fieldtype::booleanfield_dod::BooleanField;
+    ///
+    /// let votes = [
+    ///     BooleanField::<&str>::from(true),
+    ///     BooleanField::from(false),
+    ///     BooleanField::new(),
+    /// ];
+    /// assert_eq!(BooleanField::at_least(&votes, 1).get_value(), Some(true));
+    /// assert_eq!(BooleanField::at_least(&votes, 2).get_value(), None);
+    /// assert_eq!(BooleanField::at_least(&votes, 3).get_value(), Some(false));
+    /// ```
+    pub fn at_least(fields: &[Self], k: usize) -> Self {
+        let data: Vec<PackedBooleanData> = fields.iter().map(|field| field.data).collect();
+        Self::from(BooleanOps::at_least(&data, k).value())
+    }
+
+    /// Decides between true and false by summing the weights of the votes for each.
+    ///
+    /// NULL votes are ignored, as are votes whose weight is zero, negative or NaN.
+    ///
+    /// # Returns
+    /// A new nullable `BooleanField` holding the side with the larger total
+    /// weight, or NULL on a tie or when no vote counts.
+    ///
+    /// # Example
+    /// ```
+    /// use dbform::libs::libs_fieldtype::booleanfield_dod::BooleanField;
+    ///
+    /// let votes = [
+    ///     (BooleanField::<&str>::from(true), 0.7),
+    ///     (BooleanField::from(false), 0.2),
+    ///     (BooleanField::from(false), 0.3),
+    ///     (BooleanField::new(), 5.0),
+    /// ];
+    /// assert_eq!(BooleanField::weighted_vote(&votes).get_value(), Some(true));
+    ///
+    /// let tie = [(BooleanField::<&str>::from(true), 1.5), (BooleanField::from(false), 1.5)];
+    /// assert_eq!(BooleanField::weighted_vote(&tie).get_value(), None);
+    /// ```
+    pub fn weighted_vote(votes: &[(Self, f64)]) -> Self {
+        let (mut trues, mut falses) = (0.0, 0.0);
+        for (field, weight) in votes {
+            // NaN compares as None, so it is skipped along with zero and negative weights.
+            if weight.partial_cmp(&0.0) != Some(Ordering::Greater) {
+                continue;
+            }
+            match field.get_value() {
+                Some(true) => trues += weight,
+                Some(false) => falses += weight,
+                None => {}
+            }
+        }
+        let value = match trues.partial_cmp(&falses) {
+            Some(Ordering::Greater) => Some(true),
+            Some(Ordering::Less) => Some(false),
+            _ => None,
+        };
+        Self::from(value)
+    }
+
+    /// Summarizes whether `fields` all hold the same value.
+    ///
+    /// # Example
+    /// ```
+    /// use dbform::libs::libs_fieldtype::booleanfield_dod::{BooleanField, ColumnSummary};
+    ///
+    /// let fields = [BooleanField::<&str>::from(true), BooleanField::from(true)];
+    /// assert_eq!(BooleanField::column_summary(&fields), ColumnSummary::AllTrue);
+    ///
+    /// let fields = [BooleanField::<&str>::from(true), BooleanField::new()];
+    /// assert_eq!(BooleanField::column_summary(&fields), ColumnSummary::Mixed);
+    ///
+    /// assert_eq!(BooleanField::<&str>::column_summary(&[]), ColumnSummary::Empty);
+    /// ```
+    pub fn column_summary(fields: &[Self]) -> ColumnSummary {
+        let data: Vec<PackedBooleanData> = fields.iter().map(|field| field.data).collect();
+        BooleanOps::column_summary(&data)
+    }
+
+    /// Infers the tightest constraints that hold across sampled `fields`, e.g. for schema inference.
+    ///
+    /// # Returns
+    /// An `InferredConstraints` where:
+    /// - `can_be_not_null` is true if no field is NULL, including for an empty slice
+    /// - `inferred_default` is the more common of TRUE and FALSE, or None on a tie
+    ///
+    /// # Example
+    /// ```
+    /// use dbform::libs::libs_fieldtype::booleanfield_dod::{BooleanField, InferredConstraints};
+    ///
+    /// let sample = [BooleanField::<&str>::from(true), BooleanField::from(false), BooleanField::from(true)];
+    /// assert_eq!(
+    ///     BooleanField::infer_constraints(&sample),
+    ///     InferredConstraints { can_be_not_null: true, inferred_default: Some(true) }
+    /// );
+    ///
+    /// let sample = [BooleanField::<&str>::from(false), BooleanField::new(), BooleanField::new()];
+    /// assert_eq!(
+    ///     BooleanField::infer_constraints(&sample),
+    ///     InferredConstraints { can_be_not_null: false, inferred_default: Some(false) }
+    /// );
+    /// ```
+    pub fn infer_constraints(fields: &[Self]) -> InferredConstraints {
+        let data: Vec<PackedBooleanData> = fields.iter().map(|field| field.data).collect();
+        BooleanOps::infer_constraints(&data)
+    }
+
+    /// XOR-reduces the values of `fields` into a parity bit.
+    ///
+    /// # Returns
+    /// A new nullable `BooleanField` that is:
+    /// - `true` if an odd number of fields are `true`
+    /// - `false` if an even number are `true`, including for an empty slice
+    /// - NULL if any field is NULL
+    ///
+    /// # Example
+    /// ```
+    /// use dbform::libs::libs_fieldtype::booleanfield_dod::BooleanField;
+    ///
+    /// let odd = [BooleanField::<&str>::from(true), BooleanField::from(false)];
+    /// assert_eq!(BooleanField::parity(&odd).get_value(), Some(true));
+    ///
+    /// let even = [BooleanField::<&str>::from(true), BooleanField::from(true)];
+    /// assert_eq!(BooleanField::parity(&even).get_value(), Some(false));
+    ///
+    /// let with_null = [BooleanField::<&str>::from(true), BooleanField::new()];
+    /// assert_eq!(BooleanField::parity(&with_null).get_value(), None);
+    ///
+    /// assert_eq!(BooleanField::<&str>::parity(&[]).get_value(), Some(false));
+    /// ```
+    pub fn parity(fields: &[Self]) -> Self {
+        let value = fields.iter().try_fold(false, |acc, field| {
+            field.get_value().map(|value| acc ^ value)
+        });
+        Self::from(value)
+    }
+
+    /// Chooses between two fields based on a three-state condition, like SQL `CASE`.
+    ///
+    /// # Returns
+    /// - `if_true` if `cond` is true
+    /// - `if_false` if `cond` is false
+    /// - a nullable NULL field if `cond` is NULL; it keeps `if_true`'s display configuration
+    ///
+    /// The chosen branch is returned as-is, including its constraints and display configuration.
+    ///
+    /// # Example
+    /// ```
+    /// use dbform::libs::libs_fieldtype::booleanfield_dod::BooleanField;
+    ///
+    /// let cond = BooleanField::<&str>::from(true);
+    /// let result = BooleanField::select(&cond, BooleanField::from(true), BooleanField::from(false));
+    /// assert_eq!(result.get_value(), Some(true));
+    ///
+    /// let cond = BooleanField::<&str>::from(false);
+    /// let result = BooleanField::select(&cond, BooleanField::from(true), BooleanField::from(false));
+    /// assert_eq!(result.get_value(), Some(false));
+    ///
+    /// let unknown = BooleanField::<&str>::new();
+    /// let result = BooleanField::select(&unknown, BooleanField::from(true), BooleanField::from(false));
+    /// assert_eq!(result.get_value(), None);
+    /// ```
+    pub fn select(cond: &Self, if_true: Self, if_false: Self) -> Self {
+        match cond.get_value() {
+            Some(true) => if_true,
+            Some(false) => if_false,
+            None => {
+                let mut field = Self::new();
+                field.display_config = if_true.display_config;
+                field
+            }
+        }
+    }
+}
+
+impl<T: fmt::Display + Clone + AsRef<str> + 'static> BooleanField<T> {
+    /// Returns the display string without allocating.
+    ///
+    /// Same output as `display`, but borrows from the display configuration
+    /// or the built-in `"true"`, `"false"` and `"NULL"` literals. Dynamic
+    /// labels from `with_dynamic_display` are computed, so they are owned.
+    ///
+    /// # Example
+    /// ```
+    /// use std::borrow::Cow;
+    /// use dbform::libs::libs_fieldtype::booleanfield_dod::BooleanField;
+    ///
+    /// let mut field = BooleanField::new().with_display("No", "Yes", None);
+    /// field.set_value(Some(true)).unwrap();
+    /// assert!(matches!(field.display_str(), Cow::Borrowed("Yes")));
+    /// ```
+    pub fn display_str(&self) -> Cow<'_, str> {
+        let value = self.data.value();
+        if let Some(dynamic) = &self.dynamic_display {
+            return Cow::Owned(dynamic.label_for(value));
+        }
+        let label = match self.display_config.as_ref() {
+            Some(config) => match value {
+                Some(true) => config.true_display.as_ref(),
+                Some(false) => config.false_display.as_ref(),
+                None => config.null_display.as_ref().map_or("NULL", |s| s.as_ref()),
+            },
+            None => match value {
+                Some(true) => "true",
+                Some(false) => "false",
+                None => "NULL",
+            },
+        };
+        Cow::Borrowed(label)
+    }
+}
 
 impl<T: fmt::Display + Clone + 'static> Default for BooleanField<T> {
     fn default() -> Self {
         Self::new()
     }
-}
+}
+
+impl<T: fmt::Display + Clone + 'static> fmt::Display for BooleanField<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.display())
+    }
+}
+
+impl<T: fmt::Display + Clone + 'static> fmt::Debug for BooleanField<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("BooleanField")
+            .field("value", &self.data.value())
+            .field("not_null", &self.data.not_null())
+            .field("default", &self.data.default_value())
+            .field("default_expr", &self.default_expr)
+            .field("null_reason", &self.null_reason())
+            .field("inverted_storage", &self.inverted_storage)
+            .finish()
+    }
+}
+
+/// Fields compare equal when their packed state matches: same value, default and
+/// NOT NULL. The display configuration, default expression and validators are
+/// ignored, so fields that differ only in presentation are equal and hash equally.
+///
+/// # Example
+/// ```
+/// use std::collections::HashSet;
+/// use dbform::libs::libs_fieldtype::booleanfield_dod::BooleanField;
+///
+/// let mut set = HashSet::new();
+/// set.insert(BooleanField::from(true).with_display("No", "Yes", None));
+/// set.insert(BooleanField::from(true).with_display("Off", "On", None));
+/// set.insert(BooleanField::from(false));
+/// assert_eq!(set.len(), 2);
+/// ```
+impl<T: fmt::Display + Clone + 'static> PartialEq for BooleanField<T> {
+    fn eq(&self, other: &Self) -> bool {
+        self.data == other.data
+    }
+}
+
+impl<T: fmt::Display + Clone + 'static> Eq for BooleanField<T> {}
+
+impl<T: fmt::Display + Clone + 'static> Hash for BooleanField<T> {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.data.hash(state);
+    }
+}
+
+#[cfg(feature = "valuable")]
+static VALUABLE_FIELDS: &[valuable::NamedField<'static>] = &[
+    valuable::NamedField::new("value"),
+    valuable::NamedField::new("not_null"),
+    valuable::NamedField::new("default"),
+];
+
+/// Records the field as a structure with a nullable `value` (`()` for NULL),
+/// `not_null` and a nullable `default`, e.g. for `tracing::field::valuable`.
+/// Display configuration, default expressions and validators are not recorded.
+#[cfg(feature = "valuable")]
+impl<T: fmt::Display + Clone + 'static> valuable::Valuable for BooleanField<T> {
+    fn as_value(&self) -> valuable::Value<'_> {
+        valuable::Value::Structable(self)
+    }
+
+    fn visit(&self, visitor: &mut dyn valuable::Visit) {
+        let (value, not_null, default) = (
+            self.data.value(),
+            self.data.not_null(),
+            self.data.default_value(),
+        );
+        visitor.visit_named_fields(&valuable::NamedValues::new(
+            VALUABLE_FIELDS,
+            &[value.as_value(), not_null.as_value(), default.as_value()],
+        ));
+    }
+}
+
+#[cfg(feature = "valuable")]
+impl<T: fmt::Display + Clone + 'static> valuable::Structable for BooleanField<T> {
+    fn definition(&self) -> valuable::StructDef<'_> {
+        valuable::StructDef::new_static("BooleanField", valuable::Fields::Named(VALUABLE_FIELDS))
+    }
+}
+
+impl<T: fmt::Display + Clone + 'static> From<bool> for BooleanField<T> {
+    fn from(value: bool) -> Self {
+        let mut field = Self::new();
+        // This should not fail
+        field.set_value(Some(value)).unwrap();
+        field
+    }
+}
+
+impl<T: fmt::Display + Clone + 'static> From<Option<bool>> for BooleanField<T> {
+    fn from(value: Option<bool>) -> Self {
+        let mut field = Self::new();
+        // This should not fail as not_null is false by default
+        field.set_value(value).unwrap();
+        field
+    }
+}
+
+/// Sums fields as a three-state OR, following the boolean-ring convention
+/// where addition is OR. The empty sum is `false`.
+///
+/// # Example
+/// ```
+/// use dbform::libs::libs_fieldtype::booleanfield_dod::BooleanField;
+///
+/// let fields = vec![BooleanField::<&str>::from(false), BooleanField::new()];
+/// assert_eq!(fields.into_iter().sum::<BooleanField<&str>>().get_value(), None);
+///
+/// let fields = vec![BooleanField::<&str>::new(), BooleanField::from(true)];
+/// assert_eq!(fields.into_iter().sum::<BooleanField<&str>>().get_value(), Some(true));
+/// ```
+impl<T: fmt::Display + Clone + 'static> Sum for BooleanField<T> {
+    fn sum<I: Iterator<Item = Self>>(iter: I) -> Self {
+        iter.fold(Self::from(false), Self::or)
+    }
+}
+
+/// Multiplies fields as a three-state AND, following the boolean-ring convention
+/// where multiplication is AND. The empty product is `true`.
+///
+/// # Example
+/// ```
+/// use dbform::libs::libs_fieldtype::booleanfield_dod::BooleanField;
+///
+/// let fields = vec![BooleanField::<&str>::from(true), BooleanField::new()];
+/// assert_eq!(fields.into_iter().product::<BooleanField<&str>>().get_value(), None);
+///
+/// let fields = vec![BooleanField::<&str>::new(), BooleanField::from(false)];
+/// assert_eq!(fields.into_iter().product::<BooleanField<&str>>().get_value(), Some(false));
+/// ```
+impl<T: fmt::Display + Clone + 'static> Product for BooleanField<T> {
+    fn product<I: Iterator<Item = Self>>(iter: I) -> Self {
+        iter.fold(Self::from(true), Self::and)
+    }
+}
+
+impl<T: fmt::Display + Clone + 'static> FromStr for BooleanField<T> {
+    type Err = BooleanFieldError;
+
+    /// Parses a nullable field from a boolean token.
+    ///
+    /// See `BooleanField::from_env` for the accepted tokens.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        parse_token(s).map(Self::from)
+    }
+}
+
+impl<T: fmt::Display + Clone + 'static> ThreeStateBool for BooleanField<T> {
+    fn value(&self) -> Option<bool> {
+        self.get_value()
+    }
+}
+
+// --- Aggregates ---
+
+/// Client-side PostgreSQL `bool_and` aggregate.
+///
+/// NULL inputs are skipped, unlike the Kleene AND of `Product`: the result is
+/// true if every non-NULL input is true, false if any is false, and NULL when
+/// there are no non-NULL inputs.
+///
+/// # Example
+/// ```
+/// use dbform::libs::libs_fieldtype::booleanfield_dod::{BoolAndAgg, BooleanField};
+///
+/// let mut agg = BoolAndAgg::new();
+/// agg.accumulate(&BooleanField::<&str>::from(true));
+/// agg.accumulate(&BooleanField::new());
+/// assert_eq!(agg.finish().get_value(), Some(true));
+///
+/// let mut agg = BoolAndAgg::new();
+/// agg.accumulate(&BooleanField::<&str>::new());
+/// assert_eq!(agg.finish().get_value(), None);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct BoolAndAgg<T: fmt::Display + Clone + 'static> {
+    state: Option<bool>,
+    _marker: PhantomData<T>,
+}
+
+impl<T: fmt::Display + Clone + 'static> BoolAndAgg<T> {
+    /// Creates an aggregate that has seen no rows
+    pub fn new() -> Self {
+        Self {
+            state: None,
+            _marker: PhantomData,
+        }
+    }
+
+    /// Adds one row; NULL rows are ignored
+    pub fn accumulate(&mut self, field: &BooleanField<T>) {
+        if let Some(value) = field.get_value() {
+            self.state = Some(self.state.unwrap_or(true) && value);
+        }
+    }
+
+    /// Returns the aggregate as a nullable field
+    pub fn finish(self) -> BooleanField<T> {
+        BooleanField::from(self.state)
+    }
+}
+
+impl<T: fmt::Display + Clone + 'static> Default for BoolAndAgg<T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Client-side PostgreSQL `bool_or` aggregate.
+///
+/// NULL inputs are skipped, unlike the Kleene OR of `Sum`: the result is
+/// true if any non-NULL input is true, false if all are false, and NULL when
+/// there are no non-NULL inputs.
+///
+/// # Example
+/// ```
+/// use dbform::libs::libs_fieldtype::booleanfield_dod::{BoolOrAgg, BooleanField};
+///
+/// let mut agg = BoolOrAgg::new();
+/// agg.accumulate(&BooleanField::<&str>::from(false));
+/// agg.accumulate(&BooleanField::new());
+/// assert_eq!(agg.finish().get_value(), Some(false));
+///
+/// assert_eq!(BoolOrAgg::<&str>::new().finish().get_value(), None);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct BoolOrAgg<T: fmt::Display + Clone + 'static> {
+    state: Option<bool>,
+    _marker: PhantomData<T>,
+}
+
+impl<T: fmt::Display + Clone + 'static> BoolOrAgg<T> {
+    /// Creates an aggregate that has seen no rows
+    pub fn new() -> Self {
+        Self {
+            state: None,
+            _marker: PhantomData,
+        }
+    }
+
+    /// Adds one row; NULL rows are ignored
+    pub fn accumulate(&mut self, field: &BooleanField<T>) {
+        if let Some(value) = field.get_value() {
+            self.state = Some(self.state.unwrap_or(false) || value);
+        }
+    }
+
+    /// Returns the aggregate as a nullable field
+    pub fn finish(self) -> BooleanField<T> {
+        BooleanField::from(self.state)
+    }
+}
+
+impl<T: fmt::Display + Clone + 'static> Default for BoolOrAgg<T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+// --- Replication ---
+
+/// A `BooleanField` tagged with a logical timestamp for last-write-wins replication.
+///
+/// Merging keeps the field with the higher timestamp. Ties are broken by the
+/// higher packed state byte, then by the display labels and default expression,
+/// so every replica picks the same winner and the merge is commutative,
+/// associative and idempotent. Validators and dynamic labels are not compared.
+#[derive(Clone, Debug)]
+pub struct TimestampedBooleanField<T: fmt::Display + Clone + 'static> {
+    field: BooleanField<T>,
+    timestamp: u64,
+}
+
+impl<T: fmt::Display + Clone + 'static> TimestampedBooleanField<T> {
+    pub fn new(field: BooleanField<T>, timestamp: u64) -> Self {
+        Self { field, timestamp }
+    }
+
+    /// Get a reference to the wrapped field
+    pub fn field(&self) -> &BooleanField<T> {
+        &self.field
+    }
+
+    /// Get the logical timestamp of the last write
+    pub fn timestamp(&self) -> u64 {
+        self.timestamp
+    }
+
+    /// Consumes the wrapper and returns the field
+    pub fn into_inner(self) -> BooleanField<T> {
+        self.field
+    }
+
+    /// Merges two replicas of the same field.
+    ///
+    /// The winner's value, default and display configuration are kept, and the
+    /// result is NOT NULL if either replica is. The timestamp is the higher of the two.
+    ///
+    /// # Example
+    /// ```
+    /// use dbform::libs::libs_fieldtype::booleanfield_dod::{BooleanField, TimestampedBooleanField};
+    ///
+    /// let older = TimestampedBooleanField::new(BooleanField::<&str>::from(true), 1);
+    /// let newer = TimestampedBooleanField::new(BooleanField::<&str>::from(false), 2);
+    ///
+    /// let merged = older.clone().merge(newer.clone());
+    /// assert_eq!(merged.field().get_value(), Some(false));
+    /// assert_eq!(merged.timestamp(), 2);
+    ///
+    /// // Merge order does not matter.
+    /// assert_eq!(newer.merge(older).field().get_value(), Some(false));
+    /// ```
+    pub fn merge(self, other: Self) -> Self {
+        // Past the timestamp and state byte, compare the rendered labels and the
+        // default expression so replicas that tie still agree on the winner.
+        let key = |replica: &Self| {
+            let config = replica.field.display_config.as_ref().map(|config| {
+                (
+                    config.false_display.to_string(),
+                    config.true_display.to_string(),
+                    config.null_display.as_ref().map(|null| null.to_string()),
+                )
+            });
+            (
+                replica.timestamp,
+                replica.field.data.to_byte(),
+                config,
+                replica.field.bidi,
+                replica.field.default_expr.clone(),
+            )
+        };
+        let (winner, loser) = if key(&self) >= key(&other) {
+            (self, other)
+        } else {
+            (other, self)
+        };
+        Self {
+            field: BooleanField {
+                data: BooleanOps::merge_prefer(&winner.field.data, &loser.field.data),
+                ..winner.field
+            },
+            timestamp: winner.timestamp,
+        }
+    }
+}
+
+// --- Records ---
+
+/// A row of named boolean columns packed into `N` contiguous bytes.
+///
+/// Only the packed state of each column is stored; display configuration and
+/// default expressions are dropped when a field is put into the record.
+///
+/// # Example
+/// ```
+/// use dbform::libs::libs_fieldtype::booleanfield_dod::{BooleanField, BooleanRecord};
+///
+/// let mut record = BooleanRecord::new(["active", "verified", "admin"]);
+/// record.set("active", &BooleanField::<&str>::new().not_null().default(true)).unwrap();
+/// record.set("admin", &BooleanField::<&str>::new().default(false)).unwrap();
+///
+/// assert_eq!(record.get::<&str>("active").unwrap().get_value(), Some(false));
+/// assert_eq!(
+///     record.to_sql_columns(),
+///     "active BOOLEAN NOT NULL DEFAULT TRUE, verified BOOLEAN, admin BOOLEAN DEFAULT FALSE"
+/// );
+/// ```
+#[derive(Clone, Copy, PartialEq, Eq, Hash, Debug)]
+pub struct BooleanRecord<const N: usize> {
+    names: [&'static str; N],
+    data: [PackedBooleanData; N],
+}
+
+impl<const N: usize> BooleanRecord<N> {
+    /// Creates a record whose columns are all nullable and NULL
+    pub fn new(names: [&'static str; N]) -> Self {
+        Self {
+            names,
+            data: [BooleanOps::new_data(); N],
+        }
+    }
+
+    /// Creates a record from named fields
+    pub fn from_fields<T: fmt::Display + Clone + 'static>(
+        columns: [(&'static str, BooleanField<T>); N],
+    ) -> Self {
+        Self {
+            names: std::array::from_fn(|i| columns[i].0),
+            data: std::array::from_fn(|i| columns[i].1.data),
+        }
+    }
+
+    /// Get the column names in declaration order
+    pub fn names(&self) -> &[&'static str; N] {
+        &self.names
+    }
+
+    /// Returns the named column as a field, or None if no such column exists
+    pub fn get<T: fmt::Display + Clone + 'static>(&self, name: &str) -> Option<BooleanField<T>> {
+        self.position(name).map(|i| {
+            let mut field = BooleanField::new();
+            field.data = self.data[i];
+            field
+        })
+    }
+
+    /// Stores the packed state of `field` in the named column
+    pub fn set<T: fmt::Display + Clone + 'static>(
+        &mut self,
+        name: &str,
+        field: &BooleanField<T>,
+    ) -> Result<(), BooleanFieldError> {
+        let i = self
+            .position(name)
+            .ok_or_else(|| BooleanFieldError::UnknownColumn(name.to_string()))?;
+        self.data[i] = field.data;
+        Ok(())
+    }
+
+    /// Returns the column definitions for all columns, separated by commas
+    pub fn to_sql_columns(&self) -> String {
+        self.names
+            .iter()
+            .zip(&self.data)
+            .map(|(name, data)| format!("{} {}", name, BooleanOps::to_sql(data)))
+            .collect::<Vec<_>>()
+            .join(", ")
+    }
+
+    fn position(&self, name: &str) -> Option<usize> {
+        self.names.iter().position(|column| *column == name)
+    }
+}
+
+// --- Pending Writes ---
+
+/// A `BooleanField` that can also be *pending* while a write is in flight.
+///
+/// Pending is stored in the packed byte itself: the two value bits are set to
+/// `11`, a pattern none of the 13 valid states use, while the NOT NULL and
+/// default bits are kept. Logical operations treat a pending operand like NULL,
+/// but a result that is unknown because of it is pending rather than NULL.
+///
+/// # Example
+/// ```
+/// use dbform::libs::libs_fieldtype::booleanfield_dod::{BooleanField, PendingBooleanField};
+///
+/// let mut field = PendingBooleanField::new(BooleanField::<&str>::from(false));
+/// field.set_pending();
+/// assert!(field.is_pending());
+/// assert!(field.field().is_none());
+///
+/// // Unknown because of the pending operand, so the result is pending too.
+/// let other = PendingBooleanField::new(BooleanField::from(true));
+/// assert!(field.clone().and(other.clone()).is_pending());
+/// // Decided regardless of the pending operand.
+/// assert_eq!(field.clone().or(other).get_value(), Some(true));
+/// assert!((!field.clone()).is_pending());
+///
+/// field.resolve(Some(true)).unwrap();
+/// assert!(!field.is_pending());
+/// assert_eq!(field.get_value(), Some(true));
+/// ```
+#[derive(Clone)]
+pub struct PendingBooleanField<T: fmt::Display + Clone + 'static> {
+    field: BooleanField<T>,
+}
+
+impl<T: fmt::Display + Clone + 'static> PendingBooleanField<T> {
+    /// Mask of the value bits in the packed byte.
+    const VALUE_BITS: u8 = 0b11;
+    /// Value bits marking a pending write; unused by the valid states.
+    const PENDING: u8 = 0b11;
+
+    pub fn new(field: BooleanField<T>) -> Self {
+        Self { field }
+    }
+
+    /// Check if a write is outstanding
+    pub fn is_pending(&self) -> bool {
+        self.field.data.0 & Self::VALUE_BITS == Self::PENDING
+    }
+
+    /// Marks a write as outstanding; the previous value is discarded
+    pub fn set_pending(&mut self) {
+        self.field.data.0 |= Self::PENDING;
+    }
+
+    /// Resolves the pending write with `value`, validated like `BooleanField::set_value`.
+    ///
+    /// On error the field stays pending. Resolving a field that is not pending
+    /// simply sets its value.
+    pub fn resolve(&mut self, value: Option<bool>) -> Result<(), String> {
+        let pending_byte = self.field.data.0;
+        // Value bits `00` (false) form a valid state for every constraint combination.
+        self.field.data.0 &= !Self::VALUE_BITS;
+        let result = self.field.set_value(value);
+        if result.is_err() {
+            self.field.data.0 = pending_byte;
+        }
+        result
+    }
+
+    /// Gets the value, or None while pending; check `is_pending` to tell it apart from NULL
+    pub fn get_value(&self) -> Option<bool> {
+        self.field().and_then(BooleanField::get_value)
+    }
+
+    /// Get the underlying field, or None while pending
+    pub fn field(&self) -> Option<&BooleanField<T>> {
+        (!self.is_pending()).then_some(&self.field)
+    }
+
+    /// Logical AND that propagates pending like NULL
+    pub fn and(self, other: Self) -> Self {
+        self.combine(other, BooleanField::and)
+    }
+
+    /// Logical OR that propagates pending like NULL
+    pub fn or(self, other: Self) -> Self {
+        self.combine(other, BooleanField::or)
+    }
+
+    /// Logical XOR that propagates pending like NULL
+    pub fn xor(self, other: Self) -> Self {
+        self.combine(other, BooleanField::xor)
+    }
+
+    /// Applies `op` with pending operands read as NULL, then marks an unknown result pending
+    fn combine(
+        self,
+        other: Self,
+        op: fn(BooleanField<T>, BooleanField<T>) -> BooleanField<T>,
+    ) -> Self {
+        let pending = self.is_pending() || other.is_pending();
+        let (not_null, _) = self.constraints();
+        let mut result = op(self.into_unknown(), other.into_unknown());
+        match result.data.value() {
+            None if pending => {
+                let (_, default, _) = result.data.get_full_state();
+                // (not_null, default, false) is always valid; then flag the value bits.
+                let resolved = encode_state(not_null, default, OptionBool::False).unwrap();
+                result.data.0 = resolved | Self::PENDING;
+            }
+            Some(_) if not_null => BooleanOps::set_not_null(&mut result.data),
+            _ => {}
+        }
+        Self::new(result)
+    }
+
+    /// Returns the NOT NULL flag and default, which stay readable while pending
+    fn constraints(&self) -> (bool, OptionBool) {
+        let (not_null, default, _) = decode_state(self.field.data.0 & !Self::VALUE_BITS)
+            .expect("Internal state of PendingBooleanField is invalid");
+        (not_null, default)
+    }
+
+    /// Converts a pending field into a nullable NULL field with the same default
+    fn into_unknown(self) -> BooleanField<T> {
+        if !self.is_pending() {
+            return self.field;
+        }
+        let (_, default) = self.constraints();
+        let mut field = self.field;
+        // A nullable field accepts a NULL value with any default.
+        field.data.0 = encode_state(false, default, OptionBool::Null).unwrap();
+        field
+    }
+}
+
+impl<T: fmt::Display + Clone + 'static> fmt::Debug for PendingBooleanField<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let (not_null, default) = self.constraints();
+        f.debug_struct("PendingBooleanField")
+            .field("pending", &self.is_pending())
+            .field("value", &self.get_value())
+            .field("not_null", &not_null)
+            .field("default", &Option::<bool>::from(default))
+            .finish()
+    }
+}
+
+impl<T: fmt::Display + Clone + 'static> Not for PendingBooleanField<T> {
+    type Output = Self;
+
+    /// Logical NOT that keeps a pending field pending
+    fn not(self) -> Self {
+        if self.is_pending() {
+            self
+        } else {
+            Self::new(self.field.not())
+        }
+    }
+}
+
+// --- Shared Display ---
+
+/// A lightweight field whose display configuration is reference-counted.
+///
+/// Holds only the packed state plus an `Rc` to the configuration, so a large
+/// form can hand one configuration to thousands of fields without cloning its
+/// labels into each. Validators, default expressions and other per-field
+/// extras of `BooleanField` are not carried over.
+///
+/// # Example
+/// ```
+/// use std::rc::Rc;
+/// use dbform::libs::libs_fieldtype::booleanfield_display::BooleanDisplayConfig;
+/// use dbform::libs::libs_fieldtype::booleanfield_dod::{BooleanField, SharedBooleanField};
+///
+/// let config = Rc::new(BooleanDisplayConfig {
+///     false_display: "No",
+///     true_display: "Yes",
+///     null_display: None,
+/// });
+/// let a = SharedBooleanField::new(&BooleanField::from(true), Some(Rc::clone(&config)));
+/// let mut b = SharedBooleanField::new(&BooleanField::new(), Some(Rc::clone(&config)));
+///
+/// assert!(Rc::ptr_eq(a.display_config().unwrap(), b.display_config().unwrap()));
+/// assert_eq!(a.display(), "Yes");
+/// assert_eq!(b.display(), "NULL");
+///
+/// b.set_value(Some(false)).unwrap();
+/// assert_eq!(b.display(), "No");
+/// ```
+#[derive(Clone, Debug)]
+pub struct SharedBooleanField<T: fmt::Display + Clone + 'static> {
+    data: PackedBooleanData,
+    display_config: Option<Rc<BooleanDisplayConfig<T>>>,
+}
+
+impl<T: fmt::Display + Clone + 'static> SharedBooleanField<T> {
+    /// Takes the packed state of `field` and attaches the shared `config`
+    pub fn new(field: &BooleanField<T>, config: Option<Rc<BooleanDisplayConfig<T>>>) -> Self {
+        Self {
+            data: field.data,
+            display_config: config,
+        }
+    }
+
+    /// Returns the shared display configuration, if any
+    pub fn display_config(&self) -> Option<&Rc<BooleanDisplayConfig<T>>> {
+        self.display_config.as_ref()
+    }
+
+    /// Replaces the shared display configuration
+    pub fn set_display_config(&mut self, config: Option<Rc<BooleanDisplayConfig<T>>>) {
+        self.display_config = config;
+    }
+
+    /// Get the current value
+    pub fn get_value(&self) -> Option<bool> {
+        self.data.value()
+    }
+
+    /// Set a new value, enforcing NOT NULL
+    pub fn set_value(&mut self, value: Option<bool>) -> Result<(), String> {
+        BooleanOps::set_value(&mut self.data, value)
+    }
+
+    /// Renders the value like `BooleanField::display`, using the shared configuration
+    pub fn display(&self) -> String {
+        let value = self.data.value();
+        match self.display_config.as_deref() {
+            Some(config) => BooleanDisplay::new(value, config).display_value(),
+            None => match value {
+                Some(true) => "true".to_string(),
+                Some(false) => "false".to_string(),
+                None => "NULL".to_string(),
+            },
+        }
+    }
+
+    /// Converts into a standalone `BooleanField` with its own copy of the configuration
+    pub fn to_field(&self) -> BooleanField<T> {
+        let mut field = BooleanField::new();
+        field.data = self.data;
+        field.display_config = self.display_config.as_deref().cloned();
+        field
+    }
+}
+
+// --- Columnar Storage ---
+
+/// A column of fields stored as one packed byte per row.
+///
+/// Like `BooleanRecord`, only the packed state of each field is stored.
+///
+/// # Example
+/// ```
+/// use dbform::libs::libs_fieldtype::booleanfield_dod::{BooleanColumn, BooleanField};
+///
+/// let mut column = BooleanColumn::with_capacity(100);
+/// let capacity = column.capacity();
+/// for i in 0..100 {
+///     column.push(&BooleanField::<&str>::from(i % 2 == 0));
+/// }
+/// assert_eq!(column.len(), 100);
+/// assert_eq!(column.capacity(), capacity);
+/// assert_eq!(column.get::<&str>(1).unwrap().get_value(), Some(false));
+///
+/// let column = BooleanColumn::from_iter_sized((0..10).map(|_| BooleanField::<&str>::new()));
+/// assert!(column.capacity() >= 10);
+/// ```
+#[derive(Clone, Debug, Default, PartialEq, Eq, Hash)]
+pub struct BooleanColumn {
+    data: Vec<PackedBooleanData>,
+}
+
+impl BooleanColumn {
+    /// Creates an empty column
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Creates an empty column with room for `n` rows
+    pub fn with_capacity(n: usize) -> Self {
+        Self {
+            data: Vec::with_capacity(n),
+        }
+    }
+
+    /// Collects fields into a column, preallocating from the iterator's size hint
+    pub fn from_iter_sized<T, I>(iter: I) -> Self
+    where
+        T: fmt::Display + Clone + 'static,
+        I: IntoIterator<Item = BooleanField<T>>,
+    {
+        let iter = iter.into_iter();
+        let mut column = Self::with_capacity(iter.size_hint().0);
+        for field in iter {
+            column.push(&field);
+        }
+        column
+    }
+
+    /// Reserves room for at least `additional` more rows
+    pub fn reserve(&mut self, additional: usize) {
+        self.data.reserve(additional);
+    }
+
+    /// Returns the number of rows the column can hold without reallocating
+    pub fn capacity(&self) -> usize {
+        self.data.capacity()
+    }
+
+    /// Returns the number of rows
+    pub fn len(&self) -> usize {
+        self.data.len()
+    }
+
+    /// Returns true if the column has no rows
+    pub fn is_empty(&self) -> bool {
+        self.data.is_empty()
+    }
+
+    /// Appends the packed state of `field`
+    pub fn push<T: fmt::Display + Clone + 'static>(&mut self, field: &BooleanField<T>) {
+        self.data.push(field.data);
+    }
+
+    /// Counts the rows holding `true`, eight rows per 64-bit word.
+    ///
+    /// # Example
+    /// ```
+    /// use dbform::libs::libs_fieldtype::booleanfield_dod::{BooleanColumn, BooleanField};
+    ///
+    /// let column = BooleanColumn::from_iter_sized(
+    ///     [Some(true), None, Some(false), Some(true)].map(BooleanField::<&str>::from),
+    /// );
+    /// assert_eq!(column.count_true(), 2);
+    /// ```
+    pub fn count_true(&self) -> usize {
+        BooleanOps::popcount_true(&self.data)
+    }
+
+    /// Computes how many bytes the column takes in each layout.
+    ///
+    /// Sizes are exact byte counts; `BooleanColumnSoA` itself rounds each
+    /// bitmap up to whole 64-bit words.
+    ///
+    /// # Example
+    /// ```
+    /// use dbform::libs::libs_fieldtype::booleanfield_dod::{BooleanColumn, BooleanField, PackingStats};
+    ///
+    /// let column = BooleanColumn::from_iter_sized((0..10).map(|_| BooleanField::<&str>::new()));
+    /// assert_eq!(
+    ///     column.packing_stats(),
+    ///     PackingStats { byte_len: 10, two_bit_len: 3, bitset_len: 4 }
+    /// );
+    /// ```
+    pub fn packing_stats(&self) -> PackingStats {
+        let len = self.data.len();
+        PackingStats {
+            byte_len: len * PackedBooleanData::SIZE,
+            two_bit_len: (len * 2).div_ceil(8),
+            bitset_len: 2 * len.div_ceil(8),
+        }
+    }
+
+    /// Row-wise three-state AND with `other`; each row keeps the constraints of `self`.
+    ///
+    /// # Returns
+    /// `Err(BooleanFieldError::LengthMismatch)` if the columns have different lengths.
+    ///
+    /// # Example
+    /// ```
+    /// use dbform::libs::libs_fieldtype::booleanfield_dod::{BooleanColumn, BooleanField};
+    ///
+    /// let a = BooleanColumn::from_iter_sized([Some(true), Some(true), None].map(BooleanField::<&str>::from));
+    /// let b = BooleanColumn::from_iter_sized([Some(true), Some(false), Some(false)].map(BooleanField::<&str>::from));
+    /// let and = a.and_with(&b).unwrap();
+    /// assert_eq!(and.get::<&str>(0).unwrap().get_value(), Some(true));
+    /// assert_eq!(and.get::<&str>(1).unwrap().get_value(), Some(false));
+    /// assert_eq!(and.get::<&str>(2).unwrap().get_value(), Some(false));
+    /// ```
+    pub fn and_with(&self, other: &Self) -> Result<Self, BooleanFieldError> {
+        BooleanOps::and_columns(&self.data, &other.data).map(|data| Self { data })
+    }
+
+    /// Row-wise three-state OR with `other`; each row keeps the constraints of `self`.
+    ///
+    /// # Returns
+    /// `Err(BooleanFieldError::LengthMismatch)` if the columns have different lengths.
+    pub fn or_with(&self, other: &Self) -> Result<Self, BooleanFieldError> {
+        BooleanOps::or_columns(&self.data, &other.data).map(|data| Self { data })
+    }
+
+    /// Counts the (true, false, NULL) rows.
+    ///
+    /// # Example
+    /// ```
+    /// use dbform::libs::libs_fieldtype::booleanfield_dod::{BooleanColumn, BooleanField};
+    ///
+    /// let column = BooleanColumn::from_iter_sized(
+    ///     [Some(true), None, Some(false), Some(true)].map(BooleanField::<&str>::from),
+    /// );
+    /// assert_eq!(column.count_values(), (2, 1, 1));
+    ///
+    /// let even = BooleanColumn::from_iter_sized([Some(true), Some(false), None].map(BooleanField::<&str>::from));
+    /// assert_eq!(even.balance(), 1.0);
+    /// ```
+    pub fn count_values(&self) -> (usize, usize, usize) {
+        BooleanOps::count_values(&self.data)
+    }
 
-impl<T: fmt::Display + Clone + 'static> fmt::Display for BooleanField<T> {
-    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        write!(f, "{}", self.display())
+    /// Shannon entropy in bits of the true/false split, ignoring NULLs:
+    /// 1.0 for a 50/50 split, 0.0 for a constant, empty or all-NULL column.
+    pub fn balance(&self) -> f64 {
+        BooleanOps::balance(&self.data)
+    }
+
+    /// Negates every row in place, keeping NULLs, defaults and NOT NULL as they are.
+    ///
+    /// # Example
+    /// ```
+    /// use dbform::libs::libs_fieldtype::booleanfield_dod::{BooleanColumn, BooleanField};
+    ///
+    /// let mut column = BooleanColumn::from_iter_sized([Some(true), None].map(BooleanField::<&str>::from));
+    /// column.negate();
+    /// assert_eq!(column.get::<&str>(0).unwrap().get_value(), Some(false));
+    /// assert_eq!(column.get::<&str>(1).unwrap().get_value(), None);
+    /// ```
+    pub fn negate(&mut self) {
+        BooleanOps::negate_column(&mut self.data);
+    }
+
+    /// Checks that `self[i] -> other[i]` holds on every row; only a TRUE row
+    /// paired with a FALSE row refutes it.
+    ///
+    /// # Returns
+    /// `Err(BooleanFieldError::LengthMismatch)` if the columns have different lengths.
+    pub fn implies(&self, other: &Self) -> Result<bool, BooleanFieldError> {
+        BooleanOps::column_implies(&self.data, &other.data)
+    }
+
+    /// Checks `values` against the constraints of each row without applying them.
+    ///
+    /// # Returns
+    /// - `Ok` with the `(index, error)` of every row that would reject its value
+    /// - `Err(BooleanFieldError::LengthMismatch)` if `values` has a different length
+    ///
+    /// # Example
+    /// ```
+    /// use dbform::libs::libs_fieldtype::booleanfield_dod::{BooleanColumn, BooleanField, BooleanFieldError};
+    ///
+    /// let column = BooleanColumn::from_iter_sized([
+    ///     BooleanField::<&str>::new().not_null(),
+    ///     BooleanField::new(),
+    /// ]);
+    /// assert_eq!(
+    ///     column.validate_values(&[None, None]),
+    ///     Ok(vec![(0, BooleanFieldError::NullNotAllowed)])
+    /// );
+    /// assert_eq!(
+    ///     column.validate_values(&[Some(true)]),
+    ///     Err(BooleanFieldError::LengthMismatch { expected: 2, found: 1 })
+    /// );
+    /// ```
+    pub fn validate_values(
+        &self,
+        values: &[Option<bool>],
+    ) -> Result<Vec<(usize, BooleanFieldError)>, BooleanFieldError> {
+        BooleanOps::validate_values(&self.data, values)
+    }
+
+    /// Returns the rows where `self` and `other` hold different values;
+    /// NULL differs from either value but equals NULL.
+    ///
+    /// # Returns
+    /// `Err(BooleanFieldError::LengthMismatch)` if the columns have different lengths.
+    ///
+    /// # Example
+    /// ```
+    /// use dbform::libs::libs_fieldtype::booleanfield_dod::{BooleanColumn, BooleanField};
+    ///
+    /// let a = BooleanColumn::from_iter_sized([Some(true), None, None].map(BooleanField::<&str>::from));
+    /// let b = BooleanColumn::from_iter_sized([Some(true), Some(false), None].map(BooleanField::<&str>::from));
+    /// assert_eq!(a.diff_indices(&b), Ok(vec![1]));
+    /// ```
+    pub fn diff_indices(&self, other: &Self) -> Result<Vec<usize>, BooleanFieldError> {
+        BooleanOps::diff_indices(&self.data, &other.data)
+    }
+
+    /// Counts how many of true, false and NULL appear (0 to 3).
+    ///
+    /// # Example
+    /// ```
+    /// use dbform::libs::libs_fieldtype::booleanfield_dod::{BooleanColumn, BooleanField};
+    ///
+    /// let column = BooleanColumn::from_iter_sized([Some(true), Some(true), None].map(BooleanField::<&str>::from));
+    /// assert_eq!(column.distinct_value_count(), 2);
+    /// ```
+    pub fn distinct_value_count(&self) -> usize {
+        BooleanOps::distinct_value_count(&self.data)
+    }
+
+    /// Counts the rows where `self` and `other` hold different values, eight rows per word.
+    ///
+    /// # Returns
+    /// `Err(BooleanFieldError::LengthMismatch)` if the columns have different lengths.
+    pub fn hamming_distance(&self, other: &Self) -> Result<usize, BooleanFieldError> {
+        BooleanOps::hamming_distance(&self.data, &other.data)
+    }
+
+    /// Makes every row NOT NULL, replacing NULL values and NULL defaults with `fill`.
+    ///
+    /// # Returns
+    /// How many NULL values were replaced; replaced defaults are not counted.
+    ///
+    /// # Example
+    /// ```
+    /// use dbform::libs::libs_fieldtype::booleanfield_dod::{BooleanColumn, BooleanField};
+    ///
+    /// let mut column = BooleanColumn::from_iter_sized([Some(true), None].map(BooleanField::<&str>::from));
+    /// assert_eq!(column.enforce_not_null(false), 1);
+    /// assert_eq!(column.get::<&str>(1).unwrap().get_value(), Some(false));
+    /// assert!(column.get::<&str>(0).unwrap().data().is_not_null());
+    /// ```
+    pub fn enforce_not_null(&mut self, fill: bool) -> usize {
+        BooleanOps::enforce_not_null_column(&mut self.data, fill)
+    }
+
+    /// Writes the column as CSV, one cell per CRLF-terminated line (RFC 4180).
+    ///
+    /// Values use the config's labels or `true`/`false` and NULL is an empty
+    /// cell. Cells containing a comma, quote or line break are quoted.
+    ///
+    /// # Example
+    /// ```
+    /// use dbform::libs::libs_fieldtype::booleanfield_dod::{BooleanColumn, BooleanField};
+    ///
+    /// let column = BooleanColumn::from_iter_sized([Some(true), None].map(BooleanField::<&str>::from));
+    /// let mut csv = Vec::new();
+    /// column.write_csv(&mut csv, None).unwrap();
+    /// assert_eq!(csv, b"true\r\n\r\n");
+    /// ```
+    pub fn write_csv<W: Write>(
+        &self,
+        w: &mut W,
+        config: Option<&BooleanDisplayConfig<String>>,
+    ) -> io::Result<()> {
+        BooleanOps::write_csv_column(&self.data, w, config)
+    }
+
+    /// Returns row `index` as a field, or None if it is out of bounds
+    pub fn get<T: fmt::Display + Clone + 'static>(&self, index: usize) -> Option<BooleanField<T>> {
+        self.data.get(index).map(|&data| {
+            let mut field = BooleanField::new();
+            field.data = data;
+            field
+        })
     }
 }
 
-impl<T: fmt::Display + Clone + 'static> fmt::Debug for BooleanField<T> {
-    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        f.debug_struct("BooleanField")
-            .field("value", &self.data.value())
-            .field("not_null", &self.data.not_null())
-            .field("default", &self.data.default_value())
-            .finish()
+/// Storage sizes in bytes of a column under different layouts, from `BooleanColumn::packing_stats`.
+///
+/// Only `byte_len` keeps each row's constraints and default; the other layouts
+/// store values alone.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct PackingStats {
+    /// One packed state byte per row, as in `BooleanColumn`
+    pub byte_len: usize,
+    /// Two bits per row, enough for true/false/NULL
+    pub two_bit_len: usize,
+    /// Separate value and validity bitmaps, one bit per row each, as in `BooleanColumnSoA`
+    pub bitset_len: usize,
+}
+
+/// A nullable boolean column stored as two bitplanes, Structure-of-Arrays style.
+///
+/// Row `i` lives in bit `i % 64` of word `i / 64` of both planes: `validity`
+/// is set for non-NULL rows and `values` is set for TRUE rows. A NULL row
+/// always has its value bit cleared, so whole-word operations never have to
+/// mask the value plane. Only values are stored, not constraints or defaults.
+/// The planes are plain `Vec<u64>` words rather than a `BitVec`, to avoid
+/// pulling in a bitvec dependency.
+///
+/// # Example
+/// ```
+/// use dbform::libs::libs_fieldtype::booleanfield_dod::BooleanColumnSoA;
+///
+/// let a: BooleanColumnSoA = [Some(true), Some(true), None, None].into_iter().collect();
+/// let b: BooleanColumnSoA = [Some(true), None, Some(false), None].into_iter().collect();
+///
+/// let both = a.and_with(&b).unwrap();
+/// assert_eq!(both.get(0), Some(true));
+/// assert_eq!(both.get(1), None);
+/// assert_eq!(both.get(2), Some(false));
+/// assert_eq!(both.get(3), None);
+/// assert_eq!(a.count_true(), 2);
+/// ```
+#[derive(Clone, Debug, Default, PartialEq, Eq, Hash)]
+pub struct BooleanColumnSoA {
+    values: Vec<u64>,
+    validity: Vec<u64>,
+    len: usize,
+}
+
+impl BooleanColumnSoA {
+    /// Creates an empty column
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Returns the number of rows
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    /// Returns true if the column has no rows
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    /// Returns the value of row `index`.
+    ///
+    /// # Panics
+    /// Panics if `index` is out of bounds.
+    pub fn get(&self, index: usize) -> Option<bool> {
+        assert!(
+            index < self.len,
+            "index {index} out of bounds for column of length {}",
+            self.len
+        );
+        let (word, bit) = (index / 64, 1u64 << (index % 64));
+        (self.validity[word] & bit != 0).then_some(self.values[word] & bit != 0)
+    }
+
+    /// Sets the value of row `index`.
+    ///
+    /// # Panics
+    /// Panics if `index` is out of bounds.
+    pub fn set(&mut self, index: usize, value: Option<bool>) {
+        assert!(
+            index < self.len,
+            "index {index} out of bounds for column of length {}",
+            self.len
+        );
+        let (word, bit) = (index / 64, 1u64 << (index % 64));
+        match value {
+            Some(value) => {
+                self.validity[word] |= bit;
+                if value {
+                    self.values[word] |= bit;
+                } else {
+                    self.values[word] &= !bit;
+                }
+            }
+            None => {
+                self.validity[word] &= !bit;
+                self.values[word] &= !bit;
+            }
+        }
+    }
+
+    /// Appends a row
+    // `usize::is_multiple_of` needs Rust 1.87.
+    #[allow(clippy::manual_is_multiple_of)]
+    pub fn push(&mut self, value: Option<bool>) {
+        if self.len % 64 == 0 {
+            self.values.push(0);
+            self.validity.push(0);
+        }
+        self.len += 1;
+        self.set(self.len - 1, value);
+    }
+
+    /// Counts the TRUE rows, one word at a time
+    pub fn count_true(&self) -> usize {
+        self.values
+            .iter()
+            .map(|word| word.count_ones() as usize)
+            .sum()
+    }
+
+    /// Row-wise three-state AND with `other`, one word at a time.
+    ///
+    /// # Returns
+    /// `Err(BooleanFieldError::LengthMismatch)` if the columns have different lengths.
+    pub fn and_with(&self, other: &Self) -> Result<Self, BooleanFieldError> {
+        if self.len != other.len {
+            return Err(BooleanFieldError::LengthMismatch {
+                expected: self.len,
+                found: other.len,
+            });
+        }
+        let mut values = Vec::with_capacity(self.values.len());
+        let mut validity = Vec::with_capacity(self.validity.len());
+        for i in 0..self.values.len() {
+            let (a_value, a_valid) = (self.values[i], self.validity[i]);
+            let (b_value, b_valid) = (other.values[i], other.validity[i]);
+            // TRUE needs both sides TRUE; a FALSE on either side decides the row.
+            let trues = a_value & b_value;
+            let falses = (a_valid & !a_value) | (b_valid & !b_value);
+            values.push(trues);
+            validity.push(trues | falses);
+        }
+        Ok(Self {
+            values,
+            validity,
+            len: self.len,
+        })
     }
 }
 
-impl<T: fmt::Display + Clone + 'static> From<bool> for BooleanField<T> {
-    fn from(value: bool) -> Self {
-        let mut field = Self::new();
-        // This should not fail
-        field.set_value(Some(value)).unwrap();
-        field
+impl FromIterator<Option<bool>> for BooleanColumnSoA {
+    fn from_iter<I: IntoIterator<Item = Option<bool>>>(iter: I) -> Self {
+        let mut column = Self::new();
+        for value in iter {
+            column.push(value);
+        }
+        column
     }
 }
 
-impl<T: fmt::Display + Clone + 'static> From<Option<bool>> for BooleanField<T> {
-    fn from(value: Option<bool>) -> Self {
-        let mut field = Self::new();
-        // This should not fail as not_null is false by default
-        field.set_value(value).unwrap();
-        field
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn packed(value: Option<bool>) -> PackedBooleanData {
+        let mut data = BooleanOps::new_data();
+        BooleanOps::set_value(&mut data, value).unwrap();
+        data
+    }
+
+    /// Deterministic pseudo-random column mixing all three values.
+    fn random_column(len: usize, mut seed: u64) -> Vec<PackedBooleanData> {
+        (0..len)
+            .map(|_| {
+                seed = seed
+                    .wrapping_mul(6364136223846793005)
+                    .wrapping_add(1442695040888963407);
+                packed(match (seed >> 33) % 3 {
+                    0 => Some(true),
+                    1 => Some(false),
+                    _ => None,
+                })
+            })
+            .collect()
+    }
+
+    #[test]
+    fn popcount_true_matches_naive_decode() {
+        for len in [0, 1, 7, 8, 9, 64, 1003] {
+            let column = random_column(len, len as u64 + 1);
+            let naive = column
+                .iter()
+                .filter(|field| field.value() == Some(true))
+                .count();
+            assert_eq!(BooleanOps::popcount_true(&column), naive, "len {}", len);
+        }
+    }
+
+    #[test]
+    fn from_env_parses_set_variable() {
+        let key = "BOOLEANFIELD_TEST_FROM_ENV_VALID";
+        env::set_var(key, " Yes ");
+        assert_eq!(
+            BooleanField::<&str>::from_env(key).unwrap().get_value(),
+            Some(true)
+        );
+        env::set_var(key, "off");
+        assert_eq!(
+            BooleanField::<&str>::from_env(key).unwrap().get_value(),
+            Some(false)
+        );
+        env::set_var(key, "");
+        assert_eq!(
+            BooleanField::<&str>::from_env(key).unwrap().get_value(),
+            None
+        );
+        env::remove_var(key);
+    }
+
+    #[test]
+    fn from_env_rejects_invalid_token() {
+        let key = "BOOLEANFIELD_TEST_FROM_ENV_INVALID";
+        env::set_var(key, "maybe");
+        assert_eq!(
+            BooleanField::<&str>::from_env(key).unwrap_err(),
+            BooleanFieldError::InvalidToken("maybe".to_string())
+        );
+        env::remove_var(key);
+    }
+
+    #[test]
+    fn timestamped_merge_is_idempotent() {
+        for data in (0u8..=255).filter_map(|byte| PackedBooleanData::from_byte(byte).ok()) {
+            let mut field = BooleanField::<&str>::new().with_display("No", "Yes", None);
+            field.data = data;
+            let replica = TimestampedBooleanField::new(field, 7);
+            let merged = replica.clone().merge(replica.clone());
+            assert_eq!(merged.field(), replica.field());
+            assert_eq!(
+                merged.field().display_config(),
+                replica.field().display_config()
+            );
+            assert_eq!(merged.timestamp(), 7);
+        }
+    }
+
+    #[test]
+    fn timestamped_merge_breaks_config_ties_deterministically() {
+        let a = TimestampedBooleanField::new(
+            BooleanField::<&str>::from(true).with_display("No", "Yes", None),
+            3,
+        );
+        let b = TimestampedBooleanField::new(
+            BooleanField::<&str>::from(true).with_display("Off", "On", None),
+            3,
+        );
+        let ab = a.clone().merge(b.clone());
+        let ba = b.merge(a);
+        assert_eq!(ab.field(), ba.field());
+        assert_eq!(ab.field().display_config(), ba.field().display_config());
+        assert_eq!(ab.field().display(), "On");
+    }
+
+    fn all_states() -> Vec<PackedBooleanData> {
+        (0u8..=255)
+            .filter_map(|byte| PackedBooleanData::from_byte(byte).ok())
+            .collect()
+    }
+
+    #[test]
+    fn and_or_columns_are_element_wise() {
+        let states = all_states();
+        let a: Vec<_> = states
+            .iter()
+            .flat_map(|&x| std::iter::repeat_n(x, states.len()))
+            .collect();
+        let b: Vec<_> = states.iter().cycle().take(a.len()).copied().collect();
+
+        let and = BooleanOps::and_columns(&a, &b).unwrap();
+        let or = BooleanOps::or_columns(&a, &b).unwrap();
+        for i in 0..a.len() {
+            assert_eq!(and[i], BooleanOps::and(&a[i], &b[i]), "row {}", i);
+            assert_eq!(or[i], BooleanOps::or(&a[i], &b[i]), "row {}", i);
+        }
+    }
+
+    #[test]
+    fn and_or_columns_reject_length_mismatch() {
+        let a = [packed(Some(true)), packed(None)];
+        let b = [packed(Some(true))];
+        assert_eq!(
+            BooleanOps::and_columns(&a, &b),
+            Err(BooleanFieldError::LengthMismatch {
+                expected: 2,
+                found: 1
+            })
+        );
+        assert_eq!(
+            BooleanOps::or_columns(&b, &a),
+            Err(BooleanFieldError::LengthMismatch {
+                expected: 1,
+                found: 2
+            })
+        );
+        let column = BooleanColumn { data: a.to_vec() };
+        assert_eq!(
+            column.and_with(&BooleanColumn { data: b.to_vec() }),
+            Err(BooleanFieldError::LengthMismatch {
+                expected: 2,
+                found: 1
+            })
+        );
+    }
+
+    #[test]
+    fn count_values_tallies_each_value() {
+        let column = random_column(1003, 42);
+        let (trues, falses, nulls) = BooleanOps::count_values(&column);
+        assert_eq!(trues + falses + nulls, column.len());
+        assert_eq!(trues, BooleanOps::popcount_true(&column));
+        assert_eq!(
+            nulls,
+            column
+                .iter()
+                .filter(|field| field.value().is_none())
+                .count()
+        );
+        assert_eq!(BooleanOps::count_values(&[]), (0, 0, 0));
+    }
+
+    #[test]
+    fn balance_is_entropy_of_true_false_split() {
+        let t = packed(Some(true));
+        let f = packed(Some(false));
+        let n = packed(None);
+        assert_eq!(BooleanOps::balance(&[]), 0.0);
+        assert_eq!(BooleanOps::balance(&[n, n]), 0.0);
+        assert_eq!(BooleanOps::balance(&[t, t, n]), 0.0);
+        assert_eq!(BooleanOps::balance(&[t, f, n]), 1.0);
+        let skewed = BooleanOps::balance(&[t, t, t, f]);
+        let expected = -(0.75f64 * 0.75f64.log2() + 0.25 * 0.25f64.log2());
+        assert!((skewed - expected).abs() < 1e-12);
+    }
+
+    #[test]
+    fn from_byte_unchecked_reads_invalid_byte_as_null() {
+        for byte in [3u8, 11, 18, 26, 32, 0xff] {
+            let field = BooleanField::<&str>::from_byte_unchecked(byte);
+            assert_eq!(field.get_value(), None, "byte {}", byte);
+            assert_eq!(field.data().default_value(), None, "byte {}", byte);
+            assert!(!field.data().is_not_null(), "byte {}", byte);
+            assert_eq!(field.state_code(), byte);
+        }
+        let valid = BooleanField::<&str>::from_byte_unchecked(21);
+        assert_eq!(valid.get_value(), Some(true));
+    }
+
+    #[cfg(feature = "tokio-postgres")]
+    #[test]
+    fn boolean_param_binds_as_postgres_boolean() {
+        use tokio_postgres::types::{IsNull, ToSql, Type};
+
+        assert!(<BooleanParam as ToSql>::accepts(&Type::BOOL));
+        assert!(!<BooleanParam as ToSql>::accepts(&Type::INT4));
+
+        let mut out = bytes::BytesMut::new();
+        let is_null = BooleanParam::True
+            .to_sql_checked(&Type::BOOL, &mut out)
+            .unwrap();
+        assert!(matches!(is_null, IsNull::No));
+        assert_eq!(&out[..], &[1]);
+
+        out.clear();
+        BooleanParam::False
+            .to_sql_checked(&Type::BOOL, &mut out)
+            .unwrap();
+        assert_eq!(&out[..], &[0]);
+
+        out.clear();
+        let is_null = BooleanParam::Null
+            .to_sql_checked(&Type::BOOL, &mut out)
+            .unwrap();
+        assert!(matches!(is_null, IsNull::Yes));
+        assert!(out.is_empty());
+    }
+
+    #[test]
+    fn negate_column_keeps_nulls_and_constraints() {
+        let states = all_states();
+        let mut negated = states.clone();
+        BooleanOps::negate_column(&mut negated);
+        for (before, after) in states.iter().zip(&negated) {
+            assert_eq!(after.value(), not_values(before.value()));
+            assert_eq!(after.default_value(), before.default_value());
+            assert_eq!(after.not_null(), before.not_null());
+            assert_eq!(*after, BooleanOps::not(before));
+        }
+        BooleanOps::negate_column(&mut negated);
+        assert_eq!(negated, states);
+    }
+
+    #[test]
+    fn column_implies_is_refuted_only_by_true_then_false() {
+        let values = [Some(true), Some(false), None];
+        for a in values {
+            for b in values {
+                let refuted = a == Some(true) && b == Some(false);
+                assert_eq!(
+                    BooleanOps::column_implies(&[packed(a)], &[packed(b)]),
+                    Ok(!refuted),
+                    "{:?} -> {:?}",
+                    a,
+                    b
+                );
+            }
+        }
+
+        let a = [packed(Some(true)), packed(None), packed(Some(false))];
+        let b = [packed(Some(true)), packed(Some(false)), packed(None)];
+        assert_eq!(BooleanOps::column_implies(&a, &b), Ok(true));
+        assert_eq!(BooleanOps::column_implies(&b, &a), Ok(true));
+        assert_eq!(BooleanOps::column_implies(&[], &[]), Ok(true));
+        assert_eq!(
+            BooleanOps::column_implies(&a, &b[..2]),
+            Err(BooleanFieldError::LengthMismatch {
+                expected: 3,
+                found: 2
+            })
+        );
+
+        let refuting = BooleanColumn {
+            data: vec![packed(Some(true)), packed(Some(true))],
+        };
+        let consequent = BooleanColumn {
+            data: vec![packed(Some(true)), packed(Some(false))],
+        };
+        assert_eq!(refuting.implies(&consequent), Ok(false));
+    }
+
+    #[cfg(feature = "valuable")]
+    #[test]
+    fn valuable_records_three_state_fields() {
+        use valuable::{NamedValues, Valuable, Value, Visit};
+
+        /// Captures each named field, reading `()` as NULL.
+        #[derive(Default)]
+        struct Recorder(Vec<(String, Option<bool>)>);
+
+        impl Visit for Recorder {
+            fn visit_value(&mut self, value: Value<'_>) {
+                if let Value::Structable(structable) = value {
+                    structable.visit(self);
+                }
+            }
+
+            fn visit_named_fields(&mut self, named_values: &NamedValues<'_>) {
+                for (field, value) in named_values {
+                    let recorded = match value {
+                        Value::Bool(value) => Some(*value),
+                        Value::Unit => None,
+                        other => panic!("unexpected value {:?}", other),
+                    };
+                    self.0.push((field.name().to_string(), recorded));
+                }
+            }
+        }
+
+        let record = |field: &BooleanField<&str>| {
+            let mut recorder = Recorder::default();
+            valuable::visit(&field.as_value(), &mut recorder);
+            recorder.0
+        };
+        let fields = |value, not_null, default| {
+            vec![
+                ("value".to_string(), value),
+                ("not_null".to_string(), Some(not_null)),
+                ("default".to_string(), default),
+            ]
+        };
+
+        assert_eq!(
+            record(&BooleanField::from(true)),
+            fields(Some(true), false, None)
+        );
+        assert_eq!(
+            record(&BooleanField::new().not_null().default(false)),
+            fields(Some(false), true, Some(false))
+        );
+        assert_eq!(record(&BooleanField::new()), fields(None, false, None));
+    }
+
+    #[test]
+    fn validate_values_reports_every_violation() {
+        let required = PackedBooleanData::from_byte(21).unwrap();
+        let nullable = packed(Some(false));
+        let corrupt = PackedBooleanData::from_byte_unchecked(3);
+        let data = [required, nullable, required, corrupt, required, nullable];
+        let values = [None, None, Some(false), Some(true), None, Some(true)];
+
+        assert_eq!(
+            BooleanOps::validate_values(&data, &values),
+            Ok(vec![
+                (0, BooleanFieldError::NullNotAllowed),
+                (3, BooleanFieldError::InvalidState(3)),
+                (4, BooleanFieldError::NullNotAllowed),
+            ])
+        );
+        assert_eq!(
+            BooleanOps::validate_values(&data[1..2], &[None]),
+            Ok(vec![])
+        );
+        assert_eq!(
+            BooleanOps::validate_values(&data, &values[..4]),
+            Err(BooleanFieldError::LengthMismatch {
+                expected: 6,
+                found: 4
+            })
+        );
+    }
+
+    #[test]
+    fn diff_indices_compares_values_only() {
+        let a = [
+            packed(Some(true)),
+            packed(Some(false)),
+            packed(None),
+            packed(None),
+            PackedBooleanData::from_byte(21).unwrap(),
+        ];
+        let b = [
+            packed(Some(true)),
+            packed(Some(true)),
+            packed(Some(false)),
+            packed(None),
+            packed(Some(true)),
+        ];
+        // Row 4 differs only in constraints, not in value.
+        assert_eq!(BooleanOps::diff_indices(&a, &b), Ok(vec![1, 2]));
+        assert_eq!(BooleanOps::diff_indices(&b, &a), Ok(vec![1, 2]));
+        assert_eq!(BooleanOps::diff_indices(&a, &a), Ok(vec![]));
+        assert_eq!(
+            BooleanOps::diff_indices(&a, &b[..3]),
+            Err(BooleanFieldError::LengthMismatch {
+                expected: a.len(),
+                found: 3
+            })
+        );
+    }
+
+    #[test]
+    fn config_fingerprint_is_pinned() {
+        let field = BooleanField::<&str>::new()
+            .not_null()
+            .default(true)
+            .with_display("No", "Yes", Some("Unknown"));
+        // Fixed values guard the byte encoding against accidental changes.
+        assert_eq!(field.config_fingerprint(), 0x3332_39ec_e6b6_6213);
+        assert_eq!(
+            BooleanField::<&str>::new().config_fingerprint(),
+            0xbe87_36a5_2efe_9c77
+        );
+
+        // The literal a NOT NULL field keeps under an expression is not part of its definition.
+        let expr = |default| {
+            BooleanField::<&str>::new()
+                .not_null()
+                .default(default)
+                .with_default_expr("now() > created_at".to_string())
+        };
+        assert_eq!(
+            expr(true).config_fingerprint(),
+            expr(false).config_fingerprint()
+        );
+        assert_ne!(
+            expr(true).config_fingerprint(),
+            BooleanField::<&str>::new()
+                .not_null()
+                .default(true)
+                .config_fingerprint()
+        );
+    }
+
+    #[test]
+    fn renderers_honor_dynamic_display() {
+        let dynamic = || {
+            DynamicDisplayConfig::new(
+                || "dyn-no".to_string(),
+                || "dyn|yes".to_string(),
+                || "dyn-null".to_string(),
+            )
+        };
+        let mut field = BooleanField::<&str>::new()
+            .with_display("No", "Yes", Some("-"))
+            .with_dynamic_display(dynamic());
+        assert_eq!(field.display(), "dyn-null");
+        assert_eq!(field.display_str(), field.display());
+        assert_eq!(field.to_markdown_cell(), "dyn-null");
+
+        field.set_value(Some(true)).unwrap();
+        assert_eq!(field.display_str(), "dyn|yes");
+        assert_eq!(field.to_markdown_cell(), "dyn\\|yes");
+        assert_eq!(field.to_string(), "dyn|yes");
+
+        let plain = BooleanField::<&str>::new().with_display("No", "Yes", None);
+        assert!(matches!(plain.display_str(), Cow::Borrowed("NULL")));
+        assert_eq!(plain.to_markdown_cell(), "");
+    }
+
+    #[test]
+    fn distinct_value_count_covers_zero_to_three() {
+        let t = packed(Some(true));
+        let f = packed(Some(false));
+        let n = packed(None);
+        assert_eq!(BooleanOps::distinct_value_count(&[]), 0);
+        assert_eq!(BooleanOps::distinct_value_count(&[n, n, n]), 1);
+        assert_eq!(BooleanOps::distinct_value_count(&[t, f, t, f]), 2);
+        assert_eq!(BooleanOps::distinct_value_count(&[t, n, t]), 2);
+        assert_eq!(BooleanOps::distinct_value_count(&[f, t, f, n]), 3);
+        // Constraints do not make otherwise equal values distinct.
+        let required_true = PackedBooleanData::from_byte(21).unwrap();
+        assert_eq!(BooleanOps::distinct_value_count(&[t, required_true]), 1);
+    }
+
+    #[test]
+    fn inverted_storage_applies_to_params_and_load_int_only() {
+        let field = BooleanField::<&str>::from(true).with_inverted_storage();
+        assert_eq!(field.to_int(), Some(0));
+        assert_eq!(field.to_param(), BooleanParam::False);
+        assert_eq!(field.to_sql_value(SqlDialect::Postgres), "FALSE");
+        let field = BooleanField::<&str>::from(false).with_inverted_storage();
+        assert_eq!(field.to_param(), BooleanParam::True);
+        let field = BooleanField::<&str>::new().with_inverted_storage();
+        assert_eq!(field.to_param(), BooleanParam::Null);
+
+        // from_int always builds a plain field.
+        let plain = BooleanField::<&str>::from_int(Some(0)).unwrap();
+        assert_eq!(plain.get_value(), Some(false));
+        assert_eq!(plain.to_param(), BooleanParam::False);
+
+        // load_int reads the stored integer through the inversion.
+        let mut inverted = BooleanField::<&str>::new().with_inverted_storage();
+        inverted.load_int(Some(0)).unwrap();
+        assert_eq!(inverted.get_value(), Some(true));
+        assert_eq!(inverted.to_int(), Some(0));
+        assert_eq!(inverted.to_param(), BooleanParam::False);
+    }
+
+    #[test]
+    fn hamming_distance_matches_naive_count() {
+        for len in [0, 1, 7, 8, 9, 64, 1003] {
+            let a = random_column(len, 7 * len as u64 + 3);
+            let mut b = random_column(len, 11 * len as u64 + 5);
+            // Flip constraints on some rows; only values should count.
+            for field in b.iter_mut().step_by(5) {
+                if field.value().is_some() {
+                    BooleanOps::set_default(field, true);
+                }
+            }
+            let naive = a
+                .iter()
+                .zip(&b)
+                .filter(|(x, y)| x.value() != y.value())
+                .count();
+            assert_eq!(
+                BooleanOps::hamming_distance(&a, &b),
+                Ok(naive),
+                "len {}",
+                len
+            );
+            assert_eq!(BooleanOps::hamming_distance(&a, &a), Ok(0));
+        }
+        assert_eq!(
+            BooleanOps::hamming_distance(&random_column(3, 1), &random_column(4, 1)),
+            Err(BooleanFieldError::LengthMismatch {
+                expected: 3,
+                found: 4
+            })
+        );
+    }
+
+    #[test]
+    fn safe_accessors_fall_back_on_invalid_bytes() {
+        for byte in (0u8..=255).filter(|&byte| decode_state(byte).is_err()) {
+            let data = PackedBooleanData::from_byte_unchecked(byte);
+            assert_eq!(
+                data.try_get_full_state(),
+                Err(BooleanFieldError::InvalidState(byte))
+            );
+            assert_eq!(data.value(), None, "byte {}", byte);
+            assert_eq!(data.default_value(), None, "byte {}", byte);
+            assert!(!data.not_null(), "byte {}", byte);
+        }
+        let valid = PackedBooleanData::from_byte(20).unwrap();
+        assert_eq!(
+            valid.try_get_full_state(),
+            Ok((true, Some(true), Some(false)))
+        );
+    }
+
+    #[test]
+    fn enforce_not_null_column_fills_nulls() {
+        for fill in [false, true] {
+            let states = all_states();
+            let mut enforced = states.clone();
+            let replaced = BooleanOps::enforce_not_null_column(&mut enforced, fill);
+            assert_eq!(
+                replaced,
+                states
+                    .iter()
+                    .filter(|field| field.value().is_none())
+                    .count()
+            );
+            for (before, after) in states.iter().zip(&enforced) {
+                assert!(after.not_null());
+                assert_eq!(after.value(), before.value().or(Some(fill)));
+                assert_eq!(after.default_value(), before.default_value().or(Some(fill)));
+            }
+            // Already NOT NULL, nothing left to replace.
+            assert_eq!(BooleanOps::enforce_not_null_column(&mut enforced, !fill), 0);
+        }
+    }
+
+    /// Splits single-column CSV into its cells, undoing RFC 4180 quoting.
+    fn parse_csv_cells(csv: &str) -> Vec<String> {
+        let mut cells = Vec::new();
+        let mut rest = csv;
+        while !rest.is_empty() {
+            let (cell, tail) = match rest.strip_prefix('"') {
+                Some(quoted) => {
+                    let mut cell = String::new();
+                    let mut chars = quoted.char_indices();
+                    let end = loop {
+                        let (i, c) = chars.next().expect("unterminated quoted cell");
+                        if c == '"' {
+                            if quoted[i + 1..].starts_with('"') {
+                                cell.push('"');
+                                chars.next();
+                            } else {
+                                break i + 1;
+                            }
+                        } else {
+                            cell.push(c);
+                        }
+                    };
+                    (cell, &quoted[end..])
+                }
+                None => {
+                    let end = rest.find("\r\n").expect("missing CRLF");
+                    (rest[..end].to_string(), &rest[end..])
+                }
+            };
+            cells.push(cell);
+            rest = tail.strip_prefix("\r\n").expect("missing CRLF");
+        }
+        cells
+    }
+
+    #[test]
+    fn write_csv_column_round_trips() {
+        let input = "true\r\nfalse\r\n\r\ntrue\r\n";
+        let parse = |csv: &str| -> Vec<PackedBooleanData> {
+            parse_csv_cells(csv)
+                .iter()
+                .map(|cell| packed(parse_token(cell).unwrap()))
+                .collect()
+        };
+        let column = parse(input);
+        let mut written = Vec::new();
+        BooleanOps::write_csv_column(&column, &mut written, None).unwrap();
+        let written = String::from_utf8(written).unwrap();
+        assert_eq!(written, input);
+        assert_eq!(parse(&written), column);
+
+        // Labels that need quoting survive the trip as well.
+        let config = BooleanDisplayConfig {
+            false_display: "no".to_string(),
+            true_display: "yes, \"sure\"\r\nreally".to_string(),
+            null_display: None,
+        };
+        let label_value = |cell: &str| match cell {
+            "" => None,
+            "no" => Some(false),
+            cell if cell == config.true_display => Some(true),
+            other => panic!("unexpected cell {:?}", other),
+        };
+        let mut written = Vec::new();
+        BooleanOps::write_csv_column(&column, &mut written, Some(&config)).unwrap();
+        let reparsed: Vec<_> = parse_csv_cells(&String::from_utf8(written).unwrap())
+            .iter()
+            .map(|cell| packed(label_value(cell)))
+            .collect();
+        assert_eq!(reparsed, column);
+    }
+
+    #[test]
+    fn repair_byte_maps_every_byte() {
+        let repaired = |byte: u8| PackedBooleanData::repair_byte(byte).map(|data| data.to_byte());
+        let expected = |byte: u8| -> Option<u8> {
+            match byte {
+                // The 13 valid states load unchanged.
+                0 | 1 | 2 | 4 | 5 | 6 | 8 | 9 | 10 | 16 | 17 | 20 | 21 => Some(byte),
+                // Nullable: stray value bits read as NULL, stray default bits as no default.
+                3 => Some(2),
+                7 => Some(6),
+                11 => Some(10),
+                12 => Some(8),
+                13 => Some(9),
+                14 | 15 => Some(10),
+                // NOT NULL: a NULL value takes the default, a NULL default takes the value.
+                18 | 19 => Some(16),
+                22 | 23 => Some(21),
+                24 | 28 => Some(16),
+                25 | 29 => Some(21),
+                // NOT NULL with neither a value nor a default.
+                26 | 27 | 30 | 31 => None,
+                // Bits 5-7 were never used.
+                _ => None,
+            }
+        };
+        for byte in 0u8..=255 {
+            assert_eq!(repaired(byte), expected(byte), "byte {}", byte);
+            assert_eq!(
+                BooleanField::<&str>::from_legacy_byte(byte).map(|field| field.state_code()),
+                expected(byte),
+                "byte {}",
+                byte
+            );
+        }
+    }
+
+    #[test]
+    fn logic_ops_follow_kleene_on_every_state() {
+        // Kleene logic orders false < NULL < true: AND is the minimum, OR the maximum.
+        let rank = |value: Option<bool>| match value {
+            Some(false) => 0,
+            None => 1,
+            Some(true) => 2,
+        };
+        let kleene_and = |a, b| if rank(a) <= rank(b) { a } else { b };
+        let kleene_or = |a, b| if rank(a) >= rank(b) { a } else { b };
+        let kleene_xor = |a: Option<bool>, b: Option<bool>| Some(a? != b?);
+
+        let states: Vec<u8> = (0u8..=255)
+            .filter(|&byte| decode_state(byte).is_ok())
+            .collect();
+        assert_eq!(states.len(), 13);
+        let field = |byte: u8| BooleanField::<&str>::from_byte_unchecked(byte);
+        let valid = |result: BooleanField<&str>| {
+            assert!(PackedBooleanData::from_byte(result.state_code()).is_ok());
+            result.get_value()
+        };
+        for &a in &states {
+            let a_value = field(a).get_value();
+            assert_eq!(
+                valid(field(a).not()),
+                a_value.map(|value| !value),
+                "NOT {}",
+                a
+            );
+            for &b in &states {
+                let b_value = field(b).get_value();
+                let and = valid(field(a).and(field(b)));
+                assert_eq!(and, kleene_and(a_value, b_value), "{} AND {}", a, b);
+                let or = valid(field(a).or(field(b)));
+                assert_eq!(or, kleene_or(a_value, b_value), "{} OR {}", a, b);
+                let xor = valid(field(a).xor(field(b)));
+                assert_eq!(xor, kleene_xor(a_value, b_value), "{} XOR {}", a, b);
+            }
+        }
+    }
+
+    #[test]
+    fn not_null_and_null_is_nullable_null() {
+        let mut required = BooleanField::<&str>::new().not_null();
+        required.set_value(Some(true)).unwrap();
+        let result = required.and(BooleanField::new());
+        assert_eq!(result.get_value(), None);
+        assert!(!result.is_not_null());
+        assert!(PackedBooleanData::from_byte(result.state_code()).is_ok());
+    }
+
+    #[test]
+    fn merge_agrees_with_constraints_compatible() {
+        let required = BooleanField::<&str>::new().not_null();
+        let nullable = BooleanField::<&str>::new();
+        assert!(!required.constraints_compatible(&nullable));
+        assert!(matches!(
+            required.clone().merge(nullable.clone()),
+            Err(BooleanFieldError::MergeConflict(_))
+        ));
+        assert!(!required.can_merge(&nullable, MergePolicy::Strict));
+        assert!(!nullable.can_merge(&required, MergePolicy::Strict));
+
+        let mut filled = BooleanField::<&str>::new().default(false);
+        filled.set_value(Some(false)).unwrap();
+        assert!(required.constraints_compatible(&filled));
+        assert!(required.can_merge(&filled, MergePolicy::Strict));
+        let merged = required.clone().merge(filled).unwrap();
+        assert!(merged.is_not_null());
+        assert_eq!(merged.get_value(), Some(false));
+
+        // Incompatible constraints always block a strict merge.
+        for state in all_states() {
+            let other = BooleanField::<&str>::from_byte_unchecked(state.to_byte());
+            if !required.constraints_compatible(&other) {
+                assert!(!required.can_merge(&other, MergePolicy::Strict));
+                assert!(required.clone().merge(other).is_err());
+            }
+        }
+    }
+
+    #[test]
+    fn pending_xor_stays_pending() {
+        let mut pending = PendingBooleanField::new(BooleanField::<&str>::from(false));
+        pending.set_pending();
+        for value in [Some(true), Some(false), None] {
+            let other = PendingBooleanField::new(BooleanField::from(value));
+            assert!(pending.clone().xor(other.clone()).is_pending());
+            assert!(other.xor(pending.clone()).is_pending());
+        }
+        assert!(pending.clone().xor(pending).is_pending());
+    }
+
+    #[test]
+    fn soa_and_with_rejects_length_mismatch() {
+        let a: BooleanColumnSoA = [Some(true), None].into_iter().collect();
+        let b: BooleanColumnSoA = [Some(true)].into_iter().collect();
+        assert_eq!(
+            a.and_with(&b),
+            Err(BooleanFieldError::LengthMismatch {
+                expected: 2,
+                found: 1
+            })
+        );
     }
 }
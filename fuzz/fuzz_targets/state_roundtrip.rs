@@ -0,0 +1,9 @@
+#![no_main]
+
+use dbform::libs::libs_fieldtype::booleanfield_dod::fuzz_state_roundtrip;
+use libfuzzer_sys::fuzz_target;
+
+// Every byte accepted as a packed state must survive decode -> encode unchanged.
+fuzz_target!(|data: &[u8]| {
+    fuzz_state_roundtrip(data);
+});